@@ -0,0 +1,117 @@
+//! Discovers the `src/aoc/year_YYYY/day_DD` folders at build time and generates
+//! the module declarations and the `SOLVERS` map for them, so adding a day is
+//! just a matter of creating its folder. Modules are declared once per day, in
+//! order, whatever order the folders are listed in. The declarations are plain
+//! `mod.rs` files in `src/aoc` and each year folder, rewritten only when they
+//! change, so that rustfmt and other tools can follow the module tree. Every day exports a `run`
+//! function (`run_parts` on its `main`, or `run_staged` with its `parse` stage)
+//! and a `PARAMS` constant (`&[]` if it has no puzzle parameters), which are
+//! registered as is, so a day missing either fails to build.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let aoc_dir = manifest_dir.join("src").join("aoc");
+
+    println!("cargo:rerun-if-changed=src/aoc");
+
+    let years = discover(&aoc_dir);
+
+    let mut year_modules = String::from(GENERATED_HEADER);
+    let mut entries = String::new();
+
+    for (year, days) in &years {
+        year_modules.push_str(&format!("pub mod year_{:04};\n", year));
+
+        let mut day_modules = String::from(GENERATED_HEADER);
+        for day in days {
+            day_modules.push_str(&format!("pub mod day_{:02};\n", day));
+            let module = format!("aoc::year_{:04}::day_{:02}", year, day);
+            entries.push_str(&format!(
+                "    m.insert(({0}, {1}), Solver {{ run: {2}::run, params: {2}::PARAMS }});\n",
+                year, day, module
+            ));
+        }
+        write_if_changed(
+            &aoc_dir.join(format!("year_{:04}", year)).join("mod.rs"),
+            &day_modules,
+        );
+    }
+    write_if_changed(&aoc_dir.join("mod.rs"), &year_modules);
+
+    let solvers = format!(
+        "pub static SOLVERS: Lazy<HashMap<(i32, i32), Solver>> = Lazy::new(|| {{
+    let mut m = HashMap::new();
+{}    m
+}});
+",
+        entries
+    );
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("solvers_map.rs"), solvers).unwrap();
+}
+
+const GENERATED_HEADER: &str = "// Generated by build.rs from the day folders under src/aoc\n\n";
+
+/// Writes a generated file unless it already has these contents, so builds
+/// don't keep touching the source tree.
+fn write_if_changed(path: &Path, contents: &str) {
+    if fs::read_to_string(path).is_ok_and(|existing| existing == contents) {
+        return;
+    }
+    fs::write(path, contents).unwrap();
+}
+
+/// Returns every year with its days (the day folders containing a `mod.rs`),
+/// sorted.
+fn discover(aoc_dir: &Path) -> Vec<(i32, Vec<i32>)> {
+    let mut years = Vec::new();
+
+    for entry in fs::read_dir(aoc_dir).unwrap().flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let year = match name
+            .strip_prefix("year_")
+            .and_then(|y| y.parse::<i32>().ok())
+        {
+            Some(year) if name == format!("year_{:04}", year) => year,
+            Some(_) => {
+                println!("cargo:warning=Skipping {}, expected year_YYYY", name);
+                continue;
+            }
+            None => continue,
+        };
+
+        let mut days = Vec::new();
+        for day_entry in fs::read_dir(entry.path()).unwrap().flatten() {
+            let day_name = day_entry.file_name().to_string_lossy().to_string();
+            let day = match day_name
+                .strip_prefix("day_")
+                .and_then(|d| d.parse::<i32>().ok())
+            {
+                Some(day) if day_name == format!("day_{:02}", day) => day,
+                // Otherwise `day_1` and `day_01` would both become `day_01`
+                Some(_) => {
+                    println!("cargo:warning=Skipping {}, expected day_DD", day_name);
+                    continue;
+                }
+                None => continue,
+            };
+
+            if day_entry.path().join("mod.rs").exists() {
+                days.push(day);
+            }
+        }
+
+        if !days.is_empty() {
+            days.sort();
+            years.push((year, days));
+        }
+    }
+
+    years.sort();
+    years
+}
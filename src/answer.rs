@@ -0,0 +1,114 @@
+use std::fmt;
+
+/// A puzzle answer returned by a solver.
+///
+/// Part functions return whatever type suits the day (`i32`, `i64`, `String`,
+/// ...), and are converted into an `Answer` at the edge via `From`. Integers
+/// are normalised so that the same number always compares equal, whatever
+/// type it was produced as.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Text(String),
+}
+
+impl Answer {
+    /// Parses an answer from text, treating anything that looks like an
+    /// integer as a number so that it compares equal to a solver's output.
+    pub fn parse(s: &str) -> Answer {
+        let s = s.trim();
+        match s.parse::<i128>() {
+            Ok(n) => Answer::from(n),
+            Err(_) => Answer::Text(s.to_string()),
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::BigInt(value),
+        }
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(n) => Answer::from(n),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::from(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers_normalise() {
+        assert_eq!(Answer::from(42i32), Answer::Int(42));
+        assert_eq!(Answer::from(42u64), Answer::from(42i128));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(
+            Answer::from(10_i128.pow(20)),
+            Answer::BigInt(100_000_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Answer::parse("1830467"), Answer::from(1830467i32));
+        assert_eq!(Answer::parse(" -12 "), Answer::Int(-12));
+        assert_eq!(
+            Answer::parse("100000000000000000000"),
+            Answer::BigInt(100_000_000_000_000_000_000)
+        );
+        assert_eq!(Answer::parse("4,6,3,5"), Answer::from("4,6,3,5"));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-7i64).to_string(), "-7");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("cdj,dhm").to_string(), "cdj,dhm");
+    }
+}
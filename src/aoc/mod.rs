@@ -1,2 +1,4 @@
+// Generated by build.rs from the day folders under src/aoc
+
 pub mod year_2024;
 pub mod year_2025;
@@ -1,23 +1,27 @@
+use std::collections::HashMap;
 use std::error::Error;
-use std::{collections::HashMap, fs};
 
-use crate::{utils, Part};
+use crate::{
+    answer::Answer,
+    input::PuzzleInput,
+    params::Param,
+    solvers::{run_parts, SolveRun},
+    utils, Part,
+};
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_01/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_01/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 pub fn part_1(input: &str) -> i32 {
@@ -60,10 +64,14 @@ pub fn part_2(input: &str) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_01/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_1(&input);
                 assert_eq!(result, 11);
@@ -77,7 +85,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 31);
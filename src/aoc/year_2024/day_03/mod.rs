@@ -1,24 +1,25 @@
 use regex::Regex;
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_parts, SolveRun};
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_03/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_03/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 pub fn part_1(input: &str) -> i32 {
@@ -55,7 +56,6 @@ pub fn part_2(input: &str) -> i32 {
                         0
                     }
                     _ => {
-                        println!("enabled: {:?}", enable);
                         let a: i32 = cap[1].parse().unwrap();
                         let b: i32 = cap[2].parse().unwrap();
                         if enable {
@@ -71,20 +71,21 @@ pub fn part_2(input: &str) -> i32 {
         })
         .collect();
 
-    println!("{:?}", res);
-
     return res.iter().sum();
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
 
-    const EXAMPLE_FILE_2: &str = "./src/aoc/year_2024/day_03/input/example2.txt";
+    const EXAMPLE_FILE: &str = "year_2024/day_03/input/example.txt";
+    const EXAMPLE_FILE_2: &str = "year_2024/day_03/input/example2.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_1(&input);
                 assert_eq!(result, 161);
@@ -98,7 +99,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE_2) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE_2)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 48);
@@ -1,26 +1,27 @@
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_parts, SolveRun};
 use crate::utils::grid;
 use crate::Part;
 use grid::Direction;
 use Direction::*;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_04/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_04/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 pub fn part_1(input: &str) -> i32 {
@@ -30,10 +31,8 @@ pub fn part_1(input: &str) -> i32 {
 
     let mut count = 0;
 
-    let directions = [N, NE, E, SE, S, SW, W, NW];
-
     for point in &x_points {
-        for &dir in &directions {
+        for dir in Direction::all() {
             let word: String = point
                 .next_points_in_direction(dir, 3)
                 .iter()
@@ -55,14 +54,7 @@ pub fn part_2(input: &str) -> i32 {
         .find_all(&'A')
         .iter()
         .filter(|point| {
-            let get_char = |dir| {
-                point
-                    .next_points_in_direction(dir, 1)
-                    .get(0)
-                    .and_then(|p| wordsearch.get(p))
-                    .copied()
-                    .unwrap_or('_')
-            };
+            let get_char = |dir| wordsearch.get(&point.step(dir)).copied().unwrap_or('_');
 
             let nw = get_char(NW);
             let ne = get_char(NE);
@@ -79,10 +71,14 @@ pub fn part_2(input: &str) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_04/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_1(&input);
                 assert_eq!(result, 18);
@@ -96,7 +92,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 9);
@@ -1,31 +1,32 @@
 use std::collections::HashMap;
 use std::error::Error;
-use std::fs;
 
-use crate::utils::grid::{self, parse_string_grid, Grid, Point};
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_parts, SolveRun};
+use crate::utils::grid::{self, parse_string_grid, Grid};
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_08/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_08/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 fn get_antenna_locations(antenna_map: Grid<char>) -> HashMap<String, Vec<grid::Point>> {
     let mut locations: HashMap<String, Vec<grid::Point>> = HashMap::new();
 
-    for (i, row) in antenna_map.data.iter().enumerate() {
+    for (i, row) in antenna_map.rows().enumerate() {
         for (j, cell) in row.iter().enumerate() {
             if *cell != '.' {
                 let key = cell.to_string();
@@ -73,16 +74,12 @@ fn get_antinodes(
         antinodes.push(point_b);
     }
 
-    let dx = point_b.x - point_a.x;
-    let dy = point_b.y - point_a.y;
+    let delta = point_b - point_a;
 
     let mut i = 1;
     loop {
-        let next_dx = dx * i;
-        let next_dy = dy * i;
-
-        let diagonal_point_a = Point::new(point_a.x - next_dx, point_a.y - next_dy);
-        let diagonal_point_b = Point::new(point_b.x + next_dx, point_b.y + next_dy);
+        let diagonal_point_a = point_a - delta * i;
+        let diagonal_point_b = point_b + delta * i;
 
         let mut in_bounds_count = 0;
 
@@ -117,7 +114,7 @@ fn get_antinodes(
 pub fn part_1(input: &str) -> i32 {
     let antenna_map = parse_string_grid(input);
 
-    let grid_size = antenna_map.data[0].len() as i32;
+    let grid_size = antenna_map.width() as i32;
 
     let locations = get_antenna_locations(antenna_map);
 
@@ -140,7 +137,7 @@ pub fn part_1(input: &str) -> i32 {
 pub fn part_2(input: &str) -> i32 {
     let antenna_map = parse_string_grid(input);
 
-    let grid_size = antenna_map.data[0].len() as i32;
+    let grid_size = antenna_map.width() as i32;
 
     let locations = get_antenna_locations(antenna_map);
 
@@ -163,10 +160,14 @@ pub fn part_2(input: &str) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_08/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_1(&input);
                 assert_eq!(result, 14);
@@ -180,7 +181,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 34);
@@ -1,26 +1,27 @@
 use std::collections::HashMap;
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_parts, SolveRun};
 use crate::utils::grid::{parse_number_grid, Grid, Point};
 use crate::Part;
 use std::collections::VecDeque;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_10/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_10/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 fn find_paths(trail_map: &Grid<u32>, start_position: Point, include_all_paths: bool) -> usize {
@@ -37,7 +38,7 @@ fn find_paths(trail_map: &Grid<u32>, start_position: Point, include_all_paths: b
             continue;
         };
 
-        let neighbours = trail_map.neighbours(&current_point, false);
+        let neighbours = trail_map.neighbours_iter(&current_point, false);
 
         for neighbour in neighbours {
             let Some(&neighbour_point_value) = trail_map.get(&neighbour) else {
@@ -106,10 +107,14 @@ pub fn part_2(input: &str) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_10/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_1(&input);
                 assert_eq!(result, 36);
@@ -123,7 +128,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 81);
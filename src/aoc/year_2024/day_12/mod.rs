@@ -1,25 +1,26 @@
 use std::collections::{HashMap, VecDeque};
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_parts, SolveRun};
 use crate::utils::grid::{self, Point};
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_12/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_12/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 #[derive(Debug)]
@@ -71,14 +72,14 @@ fn get_tile_corners(p: Point, farm_map: &grid::Grid<char>) -> usize {
     // ..x
     // .xx
 
-    let north = p.next_points_in_direction(grid::Direction::N, 1)[0];
-    let north_east = p.next_points_in_direction(grid::Direction::NE, 1)[0];
-    let east = p.next_points_in_direction(grid::Direction::E, 1)[0];
-    let south_east = p.next_points_in_direction(grid::Direction::SE, 1)[0];
-    let sout = p.next_points_in_direction(grid::Direction::S, 1)[0];
-    let south_west = p.next_points_in_direction(grid::Direction::SW, 1)[0];
-    let west = p.next_points_in_direction(grid::Direction::W, 1)[0];
-    let north_west = p.next_points_in_direction(grid::Direction::NW, 1)[0];
+    let north = p.step(grid::Direction::N);
+    let north_east = p.step(grid::Direction::NE);
+    let east = p.step(grid::Direction::E);
+    let south_east = p.step(grid::Direction::SE);
+    let sout = p.step(grid::Direction::S);
+    let south_west = p.step(grid::Direction::SW);
+    let west = p.step(grid::Direction::W);
+    let north_west = p.step(grid::Direction::NW);
 
     let n: char = *farm_map.get(&north).unwrap_or(&'_');
     let ne: char = *farm_map.get(&north_east).unwrap_or(&'_');
@@ -201,12 +202,10 @@ fn get_region(p: Point, farm_map: &grid::Grid<char>) -> Region {
 fn get_all_regions(farm_map: &grid::Grid<char>) -> Vec<Region> {
     let mut checked_points: HashMap<grid::Point, bool> = HashMap::new();
 
-    let grid_size: usize = farm_map.data.len();
-
     let mut regions: Vec<Region> = Vec::new();
 
-    for row in 0..grid_size {
-        for col in 0..grid_size {
+    for row in 0..farm_map.height() {
+        for col in 0..farm_map.width() {
             let p = Point::new(col as i32, row as i32);
             if checked_points.get_key_value(&p).is_some() {
                 continue;
@@ -249,10 +248,14 @@ pub fn part_2(input: &str) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_12/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_1(&input);
                 assert_eq!(result, 1930);
@@ -266,7 +269,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 1206);
@@ -1,49 +1,45 @@
 use std::error::Error;
-use std::fs;
 
 use nalgebra::{DMatrix, DVector};
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_parts, SolveRun};
+use crate::utils::grid::Point;
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_13/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_13/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
-}
-
-#[derive(Debug)]
-struct XYvalues {
-    x: i64,
-    y: i64,
+    Ok(res.into())
 }
 
 #[derive(Debug)]
 struct Config {
-    button_a: XYvalues,
-    button_b: XYvalues,
-    prize: XYvalues,
+    button_a: Point<i64>,
+    button_b: Point<i64>,
+    prize: Point<i64>,
 }
 
-fn extract_coords(line: &str) -> XYvalues {
+fn extract_coords(line: &str) -> Point<i64> {
     let re = regex::Regex::new(r"X[+=](\d+),\s*Y[+=](\d+)").unwrap();
     re.captures(line)
         .and_then(|caps| {
             let x = caps.get(1)?.as_str().parse().ok()?;
             let y = caps.get(2)?.as_str().parse().ok()?;
-            Some(XYvalues { x, y })
+            Some(Point::new(x, y))
         })
-        .unwrap_or(XYvalues { x: 0, y: 0 })
+        .unwrap_or(Point::new(0, 0))
 }
 
 fn parse_configurations(sections: Vec<&str>) -> Vec<Config> {
@@ -111,14 +107,17 @@ pub fn part_2(input: &str) -> i64 {
 
     let mut total = 0;
 
+    let offset = Point::new(10000000000000, 10000000000000);
+
     for config in button_configurations {
+        let prize = config.prize + offset;
         let (x, y) = solve_linear_equation(
             config.button_a.x as f64,
             config.button_b.x as f64,
             config.button_a.y as f64,
             config.button_b.y as f64,
-            config.prize.x as f64 + 10000000000000.0,
-            config.prize.y as f64 + 10000000000000.0,
+            prize.x as f64,
+            prize.y as f64,
         );
 
         if x.fract() == 0.0 && y.fract() == 0.0 {
@@ -132,10 +131,14 @@ pub fn part_2(input: &str) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_13/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_1(&input);
                 assert_eq!(result, 480);
@@ -149,7 +152,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 875318608908);
@@ -1,40 +1,51 @@
 use std::collections::HashMap;
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::{Param, Params};
+use crate::solvers::{run_parts, SolveRun};
 use crate::utils::grid::Point;
 use crate::utils::parsing;
 use crate::Part;
 use regex::Regex;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_14/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_14/input/input.txt";
-
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "width",
+        input: 101,
+        examples: &[11],
+    },
+    Param {
+        name: "height",
+        input: 103,
+        examples: &[7],
+    },
+];
+
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents, example),
-        Part::Two => part_2(&contents, example),
+        Part::One => part_1(&input.text, &input.params),
+        Part::Two => part_2(&input.text, &input.params),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 #[derive(Debug)]
 struct Robot {
     point: Point,
-    vx: i32,
-    vy: i32,
+    velocity: Point,
 }
 
 impl Robot {
     pub fn move_robot(&mut self, height: i32, width: i32) {
-        self.point.x = (self.point.x + self.vx).rem_euclid(width);
-        self.point.y = (self.point.y + self.vy).rem_euclid(height);
+        let next = self.point + self.velocity;
+        self.point = Point::new(next.x.rem_euclid(width), next.y.rem_euclid(height));
     }
 
     pub fn get_quadrant(&mut self, height: i32, width: i32) -> Option<&'static str> {
@@ -69,9 +80,8 @@ fn parse_robots(input: &str) -> Vec<Robot> {
             let v_x = caps[3].parse::<i32>().unwrap();
             let v_y = caps[4].parse::<i32>().unwrap();
             robots.push(Robot {
-                point: Point { x: p_x, y: p_y },
-                vx: v_x,
-                vy: v_y,
+                point: Point::new(p_x, p_y),
+                velocity: Point::new(v_x, v_y),
             });
         }
     }
@@ -97,10 +107,11 @@ fn get_map(_robots: &Vec<Robot>, height: i32, width: i32) -> Vec<Vec<char>> {
 //     println!();
 // }
 
-pub fn part_1(input: &str, example: bool) -> i32 {
+pub fn part_1(input: &str, params: &Params) -> i32 {
     let mut robots = parse_robots(input);
 
-    let (height, width) = if example { (7, 11) } else { (103, 101) };
+    let width: i32 = params.get("width");
+    let height: i32 = params.get("height");
 
     for _ in 0..=99 {
         for robot in robots.iter_mut() {
@@ -123,10 +134,11 @@ pub fn part_1(input: &str, example: bool) -> i32 {
         * quads.get("SW").unwrap_or(&0);
 }
 
-pub fn part_2(input: &str, example: bool) -> i32 {
+pub fn part_2(input: &str, params: &Params) -> i32 {
     let mut robots = parse_robots(input);
 
-    let (height, width) = if example { (7, 11) } else { (103, 101) };
+    let width: i32 = params.get("width");
+    let height: i32 = params.get("height");
 
     for step in 0..=10000 {
         for robot in robots.iter_mut() {
@@ -163,12 +175,16 @@ pub fn part_2(input: &str, example: bool) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_14/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
-                let result = part_1(&input, true);
+                let result = part_1(&input, &Params::defaults(PARAMS, Some(1)));
                 assert_eq!(result, 12);
             }
             Err(e) => {
@@ -180,9 +196,9 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
-                let result = part_2(&input, true);
+                let result = part_2(&input, &Params::defaults(PARAMS, Some(1)));
                 assert_eq!(result, 0);
             }
             Err(e) => {
@@ -1,25 +1,35 @@
 use std::collections::HashMap;
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_staged, SolveRun};
 use crate::utils::grid::{self, Direction, Grid, Point};
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_15/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_15/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_staged(parts, input, parse, main)
+}
 
-    let contents = fs::read_to_string(input_file)?;
+pub fn parse(input: &PuzzleInput) -> Result<Warehouse, Box<dyn Error>> {
+    Ok(parse_input(&input.text))
+}
 
+pub fn main(part: Part, warehouse: &Warehouse) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(warehouse),
+        Part::Two => part_2(warehouse),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
+}
+
+pub struct Warehouse {
+    map: Grid<char>,
+    directions: Vec<Direction>,
 }
 
 struct Simulation {
@@ -79,7 +89,7 @@ impl Simulation {
 
             for current_box in &current_boxes {
                 for p in &current_box.points {
-                    let next_point = p.next_points_in_direction(direction, 1)[0];
+                    let next_point = p.step(direction);
                     if let Some(next_box) = position_to_box.get(&next_point) {
                         if !visited.contains_key(&next_box.id) {
                             chain.push(next_box.id);
@@ -104,7 +114,7 @@ impl Simulation {
         for bx in self.boxes.iter_mut().filter(|b| box_chain.contains(&b.id)) {
             for point in &mut bx.points {
                 // is there an obstacle in that direction?
-                let point_to_check = point.next_points_in_direction(direction, 1)[0];
+                let point_to_check = point.step(direction);
                 if self.obstacles.contains_key(&point_to_check) {
                     return true;
                 }
@@ -114,7 +124,7 @@ impl Simulation {
     }
 
     fn move_robot(&mut self, direction: Direction) {
-        let next_pos = self.robot_position.next_points_in_direction(direction, 1)[0];
+        let next_pos = self.robot_position.step(direction);
 
         // If obstacle, can't move
         if self.obstacles.contains_key(&next_pos) {
@@ -133,7 +143,7 @@ impl Simulation {
             // Move each box in the chain by one step in the given direction
             for bx in self.boxes.iter_mut().filter(|b| chain_ids.contains(&b.id)) {
                 for point in &mut bx.points {
-                    *point = point.next_points_in_direction(direction, 1)[0];
+                    *point = point.step(direction);
                 }
             }
         }
@@ -155,19 +165,19 @@ impl std::fmt::Display for Simulation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut print_grid = self.warehouse_map.clone();
 
-        print_grid.data[self.robot_position.y as usize][self.robot_position.x as usize] = '@';
+        print_grid[self.robot_position] = '@';
 
         for b in &self.boxes {
             let p = b.points[0];
             if b.points.len() == 1 {
-                print_grid.data[p.y as usize][p.x as usize] = 'O';
+                print_grid[p] = 'O';
             } else {
-                print_grid.data[p.y as usize][p.x as usize] = '[';
-                print_grid.data[p.y as usize][(p.x + 1) as usize] = ']';
+                print_grid[p] = '[';
+                print_grid[Point::new(p.x + 1, p.y)] = ']';
             }
         }
 
-        for row in &print_grid.data {
+        for row in print_grid.rows() {
             for item in row {
                 write!(f, "{}", item)?;
             }
@@ -178,14 +188,14 @@ impl std::fmt::Display for Simulation {
     }
 }
 
-fn parse_map(input: &str, scale: Scale) -> Grid<char> {
+fn scale_map(map: &Grid<char>, scale: Scale) -> Grid<char> {
     match scale {
-        Scale::One => grid::parse_string_grid(input),
+        Scale::One => map.clone(),
         Scale::Two => {
-            let rows: Vec<Vec<char>> = input
-                .lines()
-                .map(|line| {
-                    line.chars()
+            let rows: Vec<Vec<char>> = map
+                .rows()
+                .map(|row| {
+                    row.iter()
                         .flat_map(|ch| match ch {
                             'O' => vec!['[', ']'],
                             '@' => vec!['@', '.'],
@@ -202,23 +212,22 @@ fn parse_map(input: &str, scale: Scale) -> Grid<char> {
     }
 }
 
-fn parse_input(
-    input: &str,
-    scale: Scale,
-) -> (
-    Grid<char>,
-    HashMap<Point, bool>,
-    Vec<WarehouseBox>,
-    Point,
-    Vec<Direction>,
-) {
+fn parse_input(input: &str) -> Warehouse {
     let parts = input.split("\n\n").collect::<Vec<_>>();
 
-    let mut warehouse_map = parse_map(input, scale);
+    let map = grid::parse_string_grid(parts[0]);
+
+    let directions: Vec<Direction> = parts[1].chars().filter_map(Direction::from_char).collect();
+
+    Warehouse { map, directions }
+}
+
+fn build_simulation(warehouse: &Warehouse, scale: Scale) -> Simulation {
+    let mut warehouse_map = scale_map(&warehouse.map, scale);
 
     let robot_position: Point = warehouse_map.find_all(&'@')[0];
 
-    warehouse_map.data[robot_position.y as usize][robot_position.x as usize] = '.';
+    warehouse_map[robot_position] = '.';
 
     let mut obstacles = HashMap::<Point, bool>::new();
     for obstacle_point in warehouse_map.find_all(&'#') {
@@ -243,47 +252,29 @@ fn parse_input(
     let mut id = 0;
     for points in box_positions {
         for p in &points {
-            warehouse_map.data[p.y as usize][p.x as usize] = '.';
+            warehouse_map[*p] = '.';
         }
         boxes.push(WarehouseBox { id, points });
         id += 1;
     }
 
-    let mut directions = Vec::<Direction>::new();
-    let direction_chars: Vec<char> = parts[1].chars().collect();
-    for dir in direction_chars {
-        match dir {
-            '<' => directions.push(Direction::W),
-            '^' => directions.push(Direction::N),
-            '>' => directions.push(Direction::E),
-            'v' => directions.push(Direction::S),
-            _ => {}
-        }
-    }
-
-    return (warehouse_map, obstacles, boxes, robot_position, directions);
+    return Simulation::new(warehouse_map, obstacles, boxes, robot_position);
 }
 
-pub fn part_1(input: &str) -> i32 {
-    let (warehouse_map, obstacles, boxes, robot_position, directions) =
-        parse_input(input, Scale::One);
+pub fn part_1(warehouse: &Warehouse) -> i32 {
+    let mut sim = build_simulation(warehouse, Scale::One);
 
-    let mut sim = Simulation::new(warehouse_map, obstacles, boxes, robot_position);
-
-    for dir in directions {
+    for &dir in &warehouse.directions {
         sim.move_robot(dir);
     }
 
     return sim.sum_gps_coords();
 }
 
-pub fn part_2(input: &str) -> i32 {
-    let (warehouse_map, obstacles, boxes, robot_position, directions) =
-        parse_input(input, Scale::Two);
-
-    let mut sim = Simulation::new(warehouse_map, obstacles, boxes, robot_position);
+pub fn part_2(warehouse: &Warehouse) -> i32 {
+    let mut sim = build_simulation(warehouse, Scale::Two);
 
-    for dir in directions {
+    for &dir in &warehouse.directions {
         sim.move_robot(dir);
     }
 
@@ -293,12 +284,16 @@ pub fn part_2(input: &str) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_15/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
-                let result = part_1(&input);
+                let result = part_1(&parse_input(&input));
                 assert_eq!(result, 10092);
             }
             Err(e) => {
@@ -310,9 +305,9 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
-                let result = part_2(&input);
+                let result = part_2(&parse_input(&input));
                 assert_eq!(result, 9021);
             }
             Err(e) => {
@@ -1,24 +1,41 @@
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::{Param, Params};
+use crate::solvers::{run_parts, SolveRun};
 use crate::utils::grid::{Grid, Point};
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_18/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_18/input/input.txt";
-
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "width",
+        input: 71,
+        examples: &[7],
+    },
+    Param {
+        name: "height",
+        input: 71,
+        examples: &[7],
+    },
+    Param {
+        name: "bytes",
+        input: 1024,
+        examples: &[12],
+    },
+];
+
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents, example),
-        Part::Two => part_2(&contents, example),
+        Part::One => part_1(&input.text, &input.params),
+        Part::Two => part_2(&input.text, &input.params),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 fn get_corrupt_points(input: &str) -> Vec<Point> {
@@ -34,9 +51,10 @@ fn get_corrupt_points(input: &str) -> Vec<Point> {
         .collect();
 }
 
-pub fn part_1(input: &str, example: bool) -> String {
-    let (height, width) = if example { (7, 7) } else { (71, 71) };
-    let num_falling = if example { 12 } else { 1024 };
+pub fn part_1(input: &str, params: &Params) -> String {
+    let width: usize = params.get("width");
+    let height: usize = params.get("height");
+    let num_falling: usize = params.get("bytes");
 
     let corrupt_points = get_corrupt_points(input);
 
@@ -56,9 +74,10 @@ pub fn part_1(input: &str, example: bool) -> String {
     return (path.len() - 1).to_string();
 }
 
-pub fn part_2(input: &str, example: bool) -> String {
-    let (height, width) = if example { (7, 7) } else { (71, 71) };
-    let num_falling = if example { 12 } else { 1024 };
+pub fn part_2(input: &str, params: &Params) -> String {
+    let width: usize = params.get("width");
+    let height: usize = params.get("height");
+    let num_falling: usize = params.get("bytes");
 
     let corrupt_points = get_corrupt_points(input);
 
@@ -104,12 +123,16 @@ pub fn part_2(input: &str, example: bool) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_18/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
-                let result = part_1(&input, true);
+                let result = part_1(&input, &Params::defaults(PARAMS, Some(1)));
                 assert_eq!(result, "22");
             }
             Err(e) => {
@@ -121,9 +144,9 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
-                let result = part_2(&input, true);
+                let result = part_2(&input, &Params::defaults(PARAMS, Some(1)));
                 assert_eq!(result, "6,1");
             }
             Err(e) => {
@@ -1,25 +1,30 @@
 use std::collections::HashMap;
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::{Param, Params};
+use crate::solvers::{run_parts, SolveRun};
 use crate::utils::grid::{self, Point};
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_20/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_20/input/input.txt";
+pub const PARAMS: &[Param] = &[Param {
+    name: "threshold",
+    input: 100,
+    examples: &[10],
+}];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents, example),
-        Part::Two => part_2(&contents, example),
+        Part::One => part_1(&input.text, &input.params),
+        Part::Two => part_2(&input.text, &input.params),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 /// Find all possible cheats from a specific point in the route.
@@ -60,8 +65,8 @@ fn get_num_cheats(route: &Vec<Point>, max_cheat_len: i32, time_saved_threshold:
     total
 }
 
-pub fn part_1(input: &str, example: bool) -> i32 {
-    let time_saved_threshold = if example { 10 } else { 100 };
+pub fn part_1(input: &str, params: &Params) -> i32 {
+    let time_saved_threshold: i32 = params.get("threshold");
     let max_cheat_len = 2;
 
     let race_map = grid::parse_string_grid(input);
@@ -76,8 +81,8 @@ pub fn part_1(input: &str, example: bool) -> i32 {
     total_cheats
 }
 
-pub fn part_2(input: &str, example: bool) -> i32 {
-    let time_saved_threshold = if example { 10 } else { 100 };
+pub fn part_2(input: &str, params: &Params) -> i32 {
+    let time_saved_threshold: i32 = params.get("threshold");
     let max_cheat_len = 20;
 
     let race_map = grid::parse_string_grid(input);
@@ -95,12 +100,16 @@ pub fn part_2(input: &str, example: bool) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_20/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
-                let result = part_1(&input, true);
+                let result = part_1(&input, &Params::defaults(PARAMS, Some(1)));
                 assert_eq!(result, 10);
             }
             Err(e) => {
@@ -112,9 +121,9 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
-                let result = part_2(&input, true);
+                let result = part_2(&input, &Params::defaults(PARAMS, Some(1)));
                 assert_eq!(result, 2268);
             }
             Err(e) => {
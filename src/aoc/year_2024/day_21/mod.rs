@@ -1,26 +1,28 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::HashMap;
 use std::error::Error;
-use std::fs;
 
-use crate::utils::grid::{self, Direction, Grid, Point};
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_parts, SolveRun};
+use crate::utils::grid::{self, Direction, Grid};
 use crate::utils::parsing;
+use crate::utils::search;
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_21/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_21/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 // Find all the shortest paths between start/end keys on the pad
@@ -28,64 +30,26 @@ fn shortest_paths(pad: &Grid<char>, start: &char, end: &char) -> Vec<Vec<char>>
     let start_point = pad.find(start).expect("Start key not found");
     let end_point = pad.find(end).expect("End key not found");
 
-    let mut queue = VecDeque::<(Point, Vec<Point>, Vec<char>)>::new();
-    let mut visited = HashSet::<Point>::new();
-
-    queue.push_back((start_point, vec![start_point], vec![]));
-    visited.insert(start_point);
-
-    let mut paths = Vec::<Vec<char>>::new();
-
-    while let Some(current) = queue.pop_front() {
-        let (current_point, current_path, current_keys) = current;
-
-        if current_point == end_point {
-            paths.push(current_keys);
-            continue;
-        }
-
-        visited.insert(current_point);
-
-        for dir in [Direction::N, Direction::E, Direction::S, Direction::W] {
-            let next_point = current_point.next_points_in_direction(dir, 1)[0];
-
-            if !pad.in_bounds(&next_point) {
-                continue;
-            }
-
-            if visited.contains(&next_point) {
-                continue;
-            }
-
-            let ch = pad.get(&next_point).expect("Point not found");
-
-            if ch == &'.' {
-                continue;
-            }
-
-            let mut next_path = current_path.clone();
-            next_path.push(next_point);
+    let result = search::bfs(
+        start_point,
+        |current| {
+            pad.neighbours_iter(current, false)
+                .filter(|p| pad[*p] != '.')
+        },
+        |current| *current == end_point,
+    );
 
-            let mut next_keys = current_keys.clone();
-            match dir {
-                Direction::N => next_keys.push('^'),
-                Direction::E => next_keys.push('>'),
-                Direction::S => next_keys.push('v'),
-                Direction::W => next_keys.push('<'),
-                _ => {}
-            }
-
-            queue.push_back((next_point, next_path, next_keys));
-        }
-    }
-
-    // Only keep the shortest paths
-    if !paths.is_empty() {
-        let min_len = paths.iter().map(|p| p.len()).min().unwrap();
-        paths.retain(|p| p.len() == min_len);
-    }
-
-    paths
+    // Turn each path into the keys that move along it
+    result
+        .all_paths()
+        .iter()
+        .map(|path| {
+            path.windows(2)
+                .filter_map(|step| Direction::cardinals().find(|dir| step[0].step(*dir) == step[1]))
+                .map(|dir| dir.to_char())
+                .collect()
+        })
+        .collect()
 }
 
 // Pre-compute all the possible paths from each key on the numpad and dirpad
@@ -291,10 +255,14 @@ pub fn part_2(input: &str) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_21/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_1(&input);
                 assert_eq!(result, 126384);
@@ -308,7 +276,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 154115708116294);
@@ -1,25 +1,26 @@
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_parts, SolveRun};
 use crate::utils::parsing;
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_22/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_22/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 fn mix(a: i64, b: i64) -> i64 {
@@ -105,12 +106,15 @@ pub fn part_2(input: &str) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
 
-    const EXAMPLE_FILE_2: &str = "./src/aoc/year_2024/day_22/input/example2.txt";
+    const EXAMPLE_FILE: &str = "year_2024/day_22/input/example.txt";
+    const EXAMPLE_FILE_2: &str = "year_2024/day_22/input/example2.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_1(&input);
                 assert_eq!(result, 37327623);
@@ -124,7 +128,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE_2) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE_2)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 23);
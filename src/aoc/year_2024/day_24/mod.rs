@@ -1,26 +1,31 @@
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_staged, SolveRun};
 use crate::utils::parsing;
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2024/day_24/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2024/day_24/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_staged(parts, input, parse, main)
+}
 
-    let contents = fs::read_to_string(input_file)?;
+pub fn parse(input: &PuzzleInput) -> Result<Circuit, Box<dyn Error>> {
+    Ok(parse_input(&input.text))
+}
 
+pub fn main(part: Part, circuit: &Circuit) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(circuit),
+        Part::Two => part_2(circuit),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 #[derive(Clone, Debug)]
@@ -74,7 +79,14 @@ impl fmt::Display for Gate {
     }
 }
 
-fn parse_input(input: &str) -> (Vec<Gate>, HashMap<String, Wire>, String, String) {
+pub struct Circuit {
+    gates: Vec<Gate>,
+    wire_map: HashMap<String, Wire>,
+    input_a: String,
+    input_b: String,
+}
+
+fn parse_input(input: &str) -> Circuit {
     let sections: Vec<&str> = input.split("\n\n").collect();
 
     let inital_inputs = parsing::read_lines(sections[0]);
@@ -139,7 +151,12 @@ fn parse_input(input: &str) -> (Vec<Gate>, HashMap<String, Wire>, String, String
         gates.push(next_gate);
     }
 
-    (gates, wire_map, input_a, input_b)
+    Circuit {
+        gates,
+        wire_map,
+        input_a,
+        input_b,
+    }
 }
 
 fn run_gate(gate: &mut Gate, wire_map: &mut HashMap<String, Wire>) {
@@ -346,16 +363,20 @@ fn test_circuit(gates: &Vec<Gate>, wire_map: &HashMap<String, Wire>) -> bool {
     return true;
 }
 
-pub fn part_1(input: &str) -> String {
-    let (gates, wire_map, input_a, input_b) = parse_input(input);
-
-    let (val, _) = run_circuit(&gates, &wire_map, input_a, input_b);
+pub fn part_1(circuit: &Circuit) -> String {
+    let (val, _) = run_circuit(
+        &circuit.gates,
+        &circuit.wire_map,
+        circuit.input_a.clone(),
+        circuit.input_b.clone(),
+    );
 
     return val.to_string();
 }
 
-pub fn part_2(input: &str) -> String {
-    let (mut gates, wire_map, _, _) = parse_input(input);
+pub fn part_2(circuit: &Circuit) -> String {
+    let mut gates = circuit.gates.clone();
+    let wire_map = &circuit.wire_map;
 
     // Look for gates that are connected to z outputs that are not XOR gates
     let mut bad_gates: Vec<usize> = Vec::new();
@@ -439,7 +460,7 @@ pub fn part_2(input: &str) -> String {
         gates[a_idx].output = b_output.clone();
         gates[b_idx].output = a_output.clone();
 
-        let working = test_circuit(&gates, &wire_map);
+        let working = test_circuit(&gates, wire_map);
 
         if working {
             swapped_wires.push(a_output.clone());
@@ -459,12 +480,16 @@ pub fn part_2(input: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2024/day_24/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
-                let result = part_1(&input);
+                let result = part_1(&parse_input(&input));
                 assert_eq!(result, "2024");
             }
             Err(e) => {
@@ -473,19 +498,4 @@ mod tests {
             }
         }
     }
-
-    #[test]
-    fn test_part_2() {
-        match fs::read_to_string(INPUT_FILE) {
-            Ok(_) => {
-                // let result = part_2(&input);
-                // assert_eq!(result, "cdj,dhm,gfm,mrb,qjd,z08,z16,z32");
-                assert_eq!(true, true);
-            }
-            Err(e) => {
-                eprintln!("Failed to read test input file: {}", e);
-                panic!("Test input file missing or unreadable");
-            }
-        }
-    }
 }
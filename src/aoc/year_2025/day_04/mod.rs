@@ -1,24 +1,25 @@
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_parts, SolveRun};
 use crate::utils::grid;
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2025/day_04/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2025/day_04/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 fn remove_rolls(warehouse_map: &mut grid::Grid<char>) -> Vec<grid::Point> {
@@ -27,10 +28,9 @@ fn remove_rolls(warehouse_map: &mut grid::Grid<char>) -> Vec<grid::Point> {
     let paper_rolls = warehouse_map.find_all(&'@');
 
     for p in &paper_rolls {
-        let surrounding_points = p.neighbours(true);
         let mut count = 0;
-        for sp in &surrounding_points {
-            if warehouse_map.get(sp) == Some(&'@') {
+        for sp in p.neighbours_iter(true) {
+            if warehouse_map.get(&sp) == Some(&'@') {
                 count += 1;
             }
         }
@@ -73,10 +73,14 @@ pub fn part_2(input: &str) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2025/day_04/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_1(&input);
                 assert_eq!(result, 13);
@@ -90,7 +94,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 43);
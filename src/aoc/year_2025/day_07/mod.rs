@@ -1,25 +1,26 @@
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_parts, SolveRun};
 use crate::utils::grid::{self, Point};
 use crate::Part;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2025/day_07/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2025/day_07/input/input.txt";
+pub const PARAMS: &[Param] = &[];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 
 fn run_tachyon_manifold(diagram: &grid::Grid<char>, start: Point) -> (i64, i64) {
@@ -36,7 +37,7 @@ fn run_tachyon_manifold(diagram: &grid::Grid<char>, start: Point) -> (i64, i64)
 
         for (pos, count) in &beam_positions {
             // Move beam south
-            let next_pos = pos.next_points_in_direction(grid::Direction::S, 1)[0];
+            let next_pos = pos.step(grid::Direction::S);
 
             if !diagram.in_bounds(&next_pos) {
                 // Remove the beam if out of bounds
@@ -55,10 +56,10 @@ fn run_tachyon_manifold(diagram: &grid::Grid<char>, start: Point) -> (i64, i64)
                 // Mark this beam for removal and add two new beams
                 to_remove.insert(pos.clone());
 
-                let left_split = next_pos.next_points_in_direction(grid::Direction::E, 1)[0];
+                let left_split = next_pos.step(grid::Direction::E);
                 *to_add.entry(left_split).or_insert(0) += *count;
 
-                let right_split = next_pos.next_points_in_direction(grid::Direction::W, 1)[0];
+                let right_split = next_pos.step(grid::Direction::W);
                 *to_add.entry(right_split).or_insert(0) += *count;
             } else {
                 // Update beam position
@@ -107,10 +108,14 @@ pub fn part_2(input: &str) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2025/day_07/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_1(&input);
                 assert_eq!(result, 21);
@@ -124,7 +129,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 40);
@@ -1,25 +1,30 @@
 use std::error::Error;
-use std::fs;
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::{Param, Params};
+use crate::solvers::{run_parts, SolveRun};
 use crate::utils::parsing;
 use crate::Part;
 use std::collections::HashMap;
 
-const EXAMPLE_FILE: &str = "./src/aoc/year_2025/day_08/input/example.txt";
-const INPUT_FILE: &str = "./src/aoc/year_2025/day_08/input/input.txt";
+pub const PARAMS: &[Param] = &[Param {
+    name: "connections",
+    input: 1000,
+    examples: &[10],
+}];
 
-pub fn main(part: Part, example: bool) -> Result<(), Box<dyn Error>> {
-    let input_file = if example { EXAMPLE_FILE } else { INPUT_FILE };
-
-    let contents = fs::read_to_string(input_file)?;
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_parts(parts, input, main)
+}
 
+pub fn main(part: Part, input: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
     let res = match part {
-        Part::One => part_1(&contents, example),
-        Part::Two => part_2(&contents),
+        Part::One => part_1(&input.text, &input.params),
+        Part::Two => part_2(&input.text),
     };
 
-    println!("{}", res);
-    Ok(())
+    Ok(res.into())
 }
 // 3D point representing a junction box
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -122,10 +127,10 @@ fn assign_or_merge_circuits<'a>(
     total_circuits
 }
 
-pub fn part_1(input: &str, example: bool) -> i64 {
+pub fn part_1(input: &str, params: &Params) -> i64 {
     let junction_points = parse_junction_points(input);
 
-    let num_connections = if example { 10 } else { 1000 };
+    let num_connections: usize = params.get("connections");
 
     let sorted_pairs = compute_sorted_pairs_by_distance(&junction_points);
 
@@ -179,12 +184,16 @@ pub fn part_2(input: &str) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_2025/day_08/input/example.txt";
 
     #[test]
     fn test_part_1() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
-                let result = part_1(&input, true);
+                let result = part_1(&input, &Params::defaults(PARAMS, Some(1)));
                 assert_eq!(result, 40);
             }
             Err(e) => {
@@ -196,7 +205,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        match fs::read_to_string(EXAMPLE_FILE) {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
             Ok(input) => {
                 let result = part_2(&input);
                 assert_eq!(result, 25272);
@@ -1,3 +1,5 @@
+// Generated by build.rs from the day folders under src/aoc
+
 pub mod day_01;
 pub mod day_02;
 pub mod day_03;
@@ -0,0 +1,89 @@
+use std::env;
+use std::error::Error;
+
+use crate::client::{Client, SESSION_EXPIRED};
+use crate::config::{Config, Profile};
+
+const DEFAULT_PROFILE: &str = "default";
+
+/// Shows the configured profiles and checks the active session token against
+/// the site.
+pub fn status() -> Result<(), Box<dyn Error>> {
+    let config = Config::load()?;
+    let current = config.current_profile().map(|(name, _)| name.to_string());
+
+    println!("Config: {}", Config::path().display());
+    if config.profiles.is_empty() {
+        println!("No profiles configured, run `auth set <token>` to add one");
+    }
+    for name in config.profiles.keys() {
+        let marker = if Some(name) == current.as_ref() {
+            "*"
+        } else {
+            " "
+        };
+        println!("{} {}", marker, name);
+    }
+
+    let (source, token) = if let Ok(token) = env::var("SESSION_TOKEN") {
+        ("SESSION_TOKEN".to_string(), token)
+    } else if let Some((name, profile)) = config.current_profile() {
+        (format!("profile '{}'", name), profile.session_token.clone())
+    } else {
+        return Err("No session token configured".into());
+    };
+
+    match validate(&token)? {
+        Some(user) => println!("Using {}: logged in as {}", source, user),
+        None => return Err(format!("Using {}: {}", source, SESSION_EXPIRED).into()),
+    }
+
+    Ok(())
+}
+
+/// Stores a session token under a profile (`default` if none is given). The
+/// profile becomes active if there isn't one already.
+pub fn set(token: &str, profile: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let name = profile.unwrap_or(DEFAULT_PROFILE);
+    let token = token.trim().trim_start_matches("session=");
+
+    match validate(token)? {
+        Some(user) => println!("Token for profile '{}' is logged in as {}", name, user),
+        None => eprintln!("Warning: the site doesn't accept this token, saving it anyway"),
+    }
+
+    let mut config = Config::load()?;
+    config.profiles.insert(
+        name.to_string(),
+        Profile {
+            session_token: token.to_string(),
+        },
+    );
+    if config.active_profile.is_none() {
+        config.active_profile = Some(name.to_string());
+    }
+    config.save()?;
+
+    println!("Saved profile '{}' to {}", name, Config::path().display());
+    Ok(())
+}
+
+/// Makes a profile the active one.
+pub fn use_profile(name: &str) -> Result<(), Box<dyn Error>> {
+    let mut config = Config::load()?;
+    if !config.profiles.contains_key(name) {
+        return Err(format!("No profile named '{}', add it with `auth set`", name).into());
+    }
+
+    config.active_profile = Some(name.to_string());
+    config.save()?;
+
+    println!("Now using profile '{}'", name);
+    Ok(())
+}
+
+/// Requests a page with the token and returns the logged in user name, or
+/// `None` if the site treats the request as logged out.
+fn validate(token: &str) -> Result<Option<String>, Box<dyn Error>> {
+    Client::with_session(token).logged_in_user()
+}
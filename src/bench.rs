@@ -0,0 +1,232 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::input::{InputSource, PuzzleInput};
+use crate::run::format_duration;
+use crate::solvers::{self, SolveOutcome};
+use crate::Part;
+
+/// Timing statistics for one solver part, as stored in results and baseline files.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub year: i32,
+    pub day: i32,
+    pub part: u8,
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl BenchResult {
+    pub fn from_samples(year: i32, day: i32, part: Part, samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort();
+
+        let n = nanos.len();
+        let median = if n.is_multiple_of(2) {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2
+        } else {
+            nanos[n / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = nanos[(n * 95).div_ceil(100) - 1];
+
+        BenchResult {
+            year,
+            day,
+            part: match part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            iterations: n,
+            min_ns: nanos[0],
+            median_ns: median,
+            mean_ns: nanos.iter().sum::<u64>() / n as u64,
+            p95_ns: p95,
+        }
+    }
+
+    fn key(&self) -> (i32, i32, u8) {
+        (self.year, self.day, self.part)
+    }
+
+    /// Percentage change in median time relative to a baseline, or `None` if the
+    /// baseline median is 0ns and there is nothing to compare with.
+    pub fn change_from(&self, baseline: &BenchResult) -> Option<f64> {
+        if baseline.median_ns == 0 {
+            return None;
+        }
+        Some(
+            (self.median_ns as f64 - baseline.median_ns as f64) / baseline.median_ns as f64 * 100.0,
+        )
+    }
+}
+
+pub struct BenchOptions<'a> {
+    pub iterations: usize,
+    pub warmup: usize,
+    pub save: &'a Path,
+    pub baseline: &'a Path,
+    pub save_baseline: bool,
+    pub threshold: f64,
+}
+
+/// Benchmarks one or both parts of a day, saves the results and compares them
+/// with the baseline. Returns an error if any part regressed by more than the
+/// threshold.
+pub fn bench(
+    year: i32,
+    day: i32,
+    parts: &[Part],
+    options: &BenchOptions,
+) -> Result<(), Box<dyn Error>> {
+    if options.iterations == 0 {
+        return Err("Iterations must be at least 1".into());
+    }
+
+    let input = InputSource::Puzzle.read(year, day)?;
+
+    let mut results = Vec::new();
+    for &part in parts {
+        for _ in 0..options.warmup {
+            time_part(year, day, part, &input)?;
+        }
+
+        let samples = (0..options.iterations)
+            .map(|_| time_part(year, day, part, &input))
+            .collect::<Result<Vec<Duration>, _>>()?;
+
+        results.push(BenchResult::from_samples(year, day, part, &samples));
+    }
+
+    let baseline = load(options.baseline)?;
+
+    println!(
+        "{:<14}{:>12}{:>12}{:>12}{:>12}{:>12}",
+        "", "min", "median", "mean", "p95", "baseline"
+    );
+
+    let mut regressions = 0;
+    for result in &results {
+        let comparison = match baseline.iter().find(|b| b.key() == result.key()) {
+            Some(base) => match result.change_from(base) {
+                Some(change) if change > options.threshold => {
+                    regressions += 1;
+                    format!("{:+.1}% REGRESSION", change)
+                }
+                Some(change) => format!("{:+.1}%", change),
+                None => "n/a".to_string(),
+            },
+            None => "-".to_string(),
+        };
+
+        println!(
+            "{:<14}{:>12}{:>12}{:>12}{:>12}{:>12}",
+            format!("{} {:02} part {}", result.year, result.day, result.part),
+            format_duration(Duration::from_nanos(result.min_ns)),
+            format_duration(Duration::from_nanos(result.median_ns)),
+            format_duration(Duration::from_nanos(result.mean_ns)),
+            format_duration(Duration::from_nanos(result.p95_ns)),
+            comparison
+        );
+    }
+
+    save(options.save, &results)?;
+    if options.save_baseline {
+        save(options.baseline, &results)?;
+    }
+
+    if regressions > 0 {
+        return Err(format!(
+            "{} part(s) regressed by more than {}%",
+            regressions, options.threshold
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn time_part(
+    year: i32,
+    day: i32,
+    part: Part,
+    input: &PuzzleInput,
+) -> Result<Duration, Box<dyn Error>> {
+    match solvers::solve_timed(year, day, part, input) {
+        (SolveOutcome::Answer(_), elapsed) => Ok(elapsed),
+        (SolveOutcome::Error(e), _) => Err(e.into()),
+        (SolveOutcome::Panic(message), _) => Err(format!("Solver panicked: {}", message).into()),
+    }
+}
+
+pub fn load(path: &Path) -> Result<Vec<BenchResult>, Box<dyn Error>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+/// Merges results into the file at `path`, replacing entries for the same
+/// (year, day, part) and keeping everything else.
+pub fn save(path: &Path, results: &[BenchResult]) -> Result<(), Box<dyn Error>> {
+    let mut merged = load(path)?;
+    merged.retain(|existing| results.iter().all(|r| r.key() != existing.key()));
+    merged.extend(results.iter().cloned());
+    merged.sort_by_key(|r| r.key());
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(&merged)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(millis: &[u64]) -> Vec<Duration> {
+        millis.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn test_statistics() {
+        let result = BenchResult::from_samples(
+            2024,
+            6,
+            Part::Two,
+            &samples(&[5, 1, 4, 2, 3, 10, 6, 7, 9, 8]),
+        );
+
+        assert_eq!(result.iterations, 10);
+        assert_eq!(result.min_ns, 1_000_000);
+        assert_eq!(result.median_ns, 5_500_000);
+        assert_eq!(result.mean_ns, 5_500_000);
+        assert_eq!(result.p95_ns, 10_000_000);
+
+        let result = BenchResult::from_samples(2024, 6, Part::One, &samples(&[3, 1, 2]));
+        assert_eq!(result.median_ns, 2_000_000);
+        assert_eq!(result.p95_ns, 3_000_000);
+    }
+
+    #[test]
+    fn test_change_from_baseline() {
+        let baseline = BenchResult::from_samples(2024, 6, Part::One, &samples(&[10]));
+        let slower = BenchResult::from_samples(2024, 6, Part::One, &samples(&[12]));
+        let faster = BenchResult::from_samples(2024, 6, Part::One, &samples(&[9]));
+
+        assert!((slower.change_from(&baseline).unwrap() - 20.0).abs() < 1e-9);
+        assert!((faster.change_from(&baseline).unwrap() + 10.0).abs() < 1e-9);
+
+        let instant = BenchResult::from_samples(2024, 6, Part::One, &samples(&[0]));
+        assert_eq!(slower.change_from(&instant), None);
+        assert_eq!(instant.change_from(&instant), None);
+    }
+}
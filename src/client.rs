@@ -0,0 +1,449 @@
+use std::env;
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use once_cell::sync::Lazy;
+
+use crate::config;
+use crate::submit::{self, SubmitOutcome};
+use crate::Part;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = concat!(
+    "github.com/jmugliston/advent-of-code-rust v",
+    env!("CARGO_PKG_VERSION")
+);
+
+pub const SESSION_EXPIRED: &str =
+    "The session has expired (or the token is invalid): log in to the site again and run `auth set <token>`";
+
+/// Default minimum time between two requests to the site.
+const DEFAULT_THROTTLE: Duration = Duration::from_secs(1);
+
+/// Throttle shared by every client in the process, so that commands making
+/// several requests (e.g. `init`) are paced too. `AOC_THROTTLE_MS` overrides
+/// the interval.
+static SHARED_THROTTLE: Lazy<Arc<Throttle>> = Lazy::new(|| {
+    let interval = env::var("AOC_THROTTLE_MS")
+        .ok()
+        .and_then(|ms| ms.parse::<u64>().ok())
+        .map_or(DEFAULT_THROTTLE, Duration::from_millis);
+    Arc::new(Throttle::new(interval))
+});
+
+/// Enforces a minimum interval between requests.
+pub struct Throttle {
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl Throttle {
+    pub fn new(min_interval: Duration) -> Self {
+        Throttle {
+            min_interval,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// Blocks until at least the minimum interval has passed since the last
+    /// call, then records this one.
+    pub fn wait(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+        if let Some(last) = *last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+}
+
+/// Whether a response body is an HTML page rather than plain text or JSON.
+fn is_html_page(body: &str) -> bool {
+    let start = body.trim_start().to_ascii_lowercase();
+    start.starts_with("<!doctype") || start.starts_with("<html")
+}
+
+/// Number of puzzles in an event: 25 until 2024, 12 from 2025 on.
+pub fn days_in(year: i32) -> i32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Checks that an event has a puzzle for the day.
+pub fn check_day(year: i32, day: i32) -> Result<(), Box<dyn Error>> {
+    if !(1..=days_in(year)).contains(&day) {
+        return Err(format!(
+            "There is no day {} in Advent of Code {}, it has days 1 to {}",
+            day,
+            year,
+            days_in(year)
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Returns when a puzzle unlocks: midnight US Eastern (UTC-5) on its day in December.
+pub fn unlock_time(year: i32, day: i32) -> Result<DateTime<Utc>, Box<dyn Error>> {
+    check_day(year, day)?;
+    let unlock = FixedOffset::west_opt(5 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(year, 12, day as u32, 0, 0, 0)
+        .single()
+        .ok_or_else(|| format!("Invalid puzzle date: day {} of {}", day, year))?;
+    Ok(unlock.with_timezone(&Utc))
+}
+
+/// Checks that a puzzle is unlocked, sleeping until it is if `wait` is set and
+/// refusing otherwise.
+pub fn ensure_unlocked(year: i32, day: i32, wait: bool) -> Result<(), Box<dyn Error>> {
+    let unlock = unlock_time(year, day)?;
+    let remaining = match (unlock - Utc::now()).to_std() {
+        Ok(remaining) => remaining,
+        Err(_) => return Ok(()), // Already unlocked
+    };
+
+    if !wait {
+        return Err(format!(
+            "Day {} of {} unlocks in {}s (at {}), use --wait to wait for it",
+            day,
+            year,
+            remaining.as_secs(),
+            unlock
+        )
+        .into());
+    }
+
+    println!(
+        "Waiting {}s for day {} of {} to unlock...",
+        remaining.as_secs(),
+        day,
+        year
+    );
+    // Allow a little slack for clock differences with the server
+    thread::sleep(remaining + Duration::from_secs(1));
+    Ok(())
+}
+
+/// HTTP client for the puzzle site, authenticated with a session cookie.
+pub struct Client {
+    base_url: String,
+    session_token: String,
+    http: reqwest::blocking::Client,
+    throttle: Arc<Throttle>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session_token: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session_token: session_token.to_string(),
+            http: reqwest::blocking::Client::new(),
+            throttle: SHARED_THROTTLE.clone(),
+        }
+    }
+
+    /// Builds a client using the configured session token, using `AOC_BASE_URL`
+    /// in place of the real site when it is set (e.g. to point at a local stub
+    /// server).
+    pub fn from_env() -> Result<Self, Box<dyn Error>> {
+        Ok(Client::with_session(&config::session_token()?))
+    }
+
+    /// Builds a client for the given session token, honouring `AOC_BASE_URL`.
+    pub fn with_session(session_token: &str) -> Self {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_string());
+        Client::new(&base_url, session_token)
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn get(&self, path: &str) -> Result<String, Box<dyn Error>> {
+        let url = format!("{}{}", self.base_url, path);
+
+        self.throttle.wait();
+        let resp = self
+            .http
+            .get(&url)
+            .header(reqwest::header::USER_AGENT, USER_AGENT)
+            .header("Cookie", format!("session={}", self.session_token))
+            .send()?;
+
+        read_body(resp)
+    }
+
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, Box<dyn Error>> {
+        let url = format!("{}{}", self.base_url, path);
+
+        self.throttle.wait();
+        let resp = self
+            .http
+            .post(&url)
+            .header(reqwest::header::USER_AGENT, USER_AGENT)
+            .header("Cookie", format!("session={}", self.session_token))
+            .form(form)
+            .send()?;
+
+        read_body(resp)
+    }
+
+    pub fn get_input(&self, year: i32, day: i32) -> Result<String, Box<dyn Error>> {
+        let input = self.get(&format!("/{}/day/{}/input", year, day))?;
+
+        // A logged out request can end up at the login page rather than an error.
+        // Inputs can start with `<` too (direction streams), so look for a page.
+        if is_html_page(&input) {
+            return Err(SESSION_EXPIRED.into());
+        }
+
+        Ok(input)
+    }
+
+    /// Returns the name of the logged in user, or `None` if the session token
+    /// isn't accepted.
+    pub fn logged_in_user(&self) -> Result<Option<String>, Box<dyn Error>> {
+        let html = self.get("/")?;
+        Ok(parse_logged_in_user(&html))
+    }
+
+    pub fn get_puzzle(&self, year: i32, day: i32) -> Result<String, Box<dyn Error>> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    /// Returns the JSON for a private leaderboard. The site asks for this to be
+    /// requested at most once every 15 minutes, see `leaderboard::fetch`.
+    pub fn get_private_leaderboard(&self, year: i32, id: u64) -> Result<String, Box<dyn Error>> {
+        let json = self.get(&format!("/{}/leaderboard/private/view/{}.json", year, id))?;
+
+        // Leaderboards the user can't see redirect to an HTML page
+        if json.trim_start().starts_with('<') {
+            return Err(format!(
+                "Leaderboard {} is not accessible with this session token",
+                id
+            )
+            .into());
+        }
+
+        Ok(json)
+    }
+
+    /// Returns the page with the logged in user's times, ranks and scores for
+    /// each day of a year.
+    pub fn get_self_leaderboard(&self, year: i32) -> Result<String, Box<dyn Error>> {
+        let html = self.get(&format!("/{}/leaderboard/self", year))?;
+        if parse_logged_in_user(&html).is_none() {
+            return Err(SESSION_EXPIRED.into());
+        }
+        Ok(html)
+    }
+
+    pub fn submit_answer(
+        &self,
+        year: i32,
+        day: i32,
+        part: Part,
+        answer: &str,
+    ) -> Result<SubmitOutcome, Box<dyn Error>> {
+        let level = part.to_string();
+
+        let body = self.post_form(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &level), ("answer", answer)],
+        )?;
+
+        Ok(submit::parse_response(&body))
+    }
+}
+
+fn read_body(resp: reqwest::blocking::Response) -> Result<String, Box<dyn Error>> {
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp
+            .text()
+            .unwrap_or_else(|_| "<failed to read body>".to_string());
+        // e.g. "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+        if status == reqwest::StatusCode::BAD_REQUEST && body.contains("log in") {
+            return Err(SESSION_EXPIRED.into());
+        }
+        eprintln!(
+            "Error: received status {} from server. Body: {}",
+            status, body
+        );
+        return Err(format!("Request failed: status {}", status).into());
+    }
+
+    Ok(resp.text()?)
+}
+
+/// Finds the user name shown in the page header, which is only there when the
+/// session is logged in.
+pub fn parse_logged_in_user(html: &str) -> Option<String> {
+    let start = html.find("<div class=\"user\">")? + "<div class=\"user\">".len();
+    let end = html[start..]
+        .find('<')
+        .map_or(html.len(), |end| start + end);
+    Some(html[start..end].trim().to_string())
+}
+
+/// Starts a server on a local port that answers a single request with the
+/// given status and body. Returns its base URL and a handle that yields the raw
+/// request it received.
+#[cfg(test)]
+pub fn stub_server(status: &str, body: &str) -> (String, thread::JoinHandle<String>) {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        loop {
+            let n = stream.read(&mut buf).unwrap();
+            request.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&request);
+            if let Some(header_end) = text.find("\r\n\r\n") {
+                let length = text
+                    .lines()
+                    .find_map(|l| {
+                        l.to_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(|v| v.trim().parse::<usize>().unwrap())
+                    })
+                    .unwrap_or(0);
+                if request.len() >= header_end + 4 + length {
+                    break;
+                }
+            }
+            if n == 0 {
+                break;
+            }
+        }
+
+        stream.write_all(response.as_bytes()).unwrap();
+
+        String::from_utf8_lossy(&request).to_string()
+    });
+
+    (base_url, server)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_throttle() {
+        let throttle = Throttle::new(Duration::from_millis(50));
+
+        let start = Instant::now();
+        throttle.wait();
+        assert!(start.elapsed() < Duration::from_millis(50));
+
+        throttle.wait();
+        throttle.wait();
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_parse_logged_in_user() {
+        let logged_in = "<header><div><h1 class=\"title-global\"><a href=\"/\">Advent of Code</a></h1>\
+            <div class=\"user\">Jane Doe <span class=\"star-count\">42*</span></div></div></header>";
+        let anonymous = "<div class=\"user\">(anonymous user #123456) <span class=\"star-count\">2*</span></div>";
+        let logged_out = "<header><nav><ul><li><a href=\"/2024/auth/login\">[Log In]</a></li></ul></nav></header>";
+
+        assert_eq!(
+            parse_logged_in_user(logged_in),
+            Some("Jane Doe".to_string())
+        );
+        assert_eq!(
+            parse_logged_in_user(anonymous),
+            Some("(anonymous user #123456)".to_string())
+        );
+        assert_eq!(parse_logged_in_user(logged_out), None);
+    }
+
+    #[test]
+    fn test_expired_session_input() {
+        let (base_url, server) = stub_server(
+            "400 Bad Request",
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        );
+
+        let client = Client::new(&base_url, "expired");
+        let err = client.get_input(2024, 1).unwrap_err();
+        server.join().unwrap();
+
+        assert_eq!(err.to_string(), SESSION_EXPIRED);
+
+        let (base_url, server) = stub_server("200 OK", "<!DOCTYPE html><html>Log In</html>");
+        let client = Client::new(&base_url, "expired");
+        let err = client.get_input(2024, 1).unwrap_err();
+        server.join().unwrap();
+
+        assert_eq!(err.to_string(), SESSION_EXPIRED);
+    }
+
+    #[test]
+    fn test_input_starting_with_angle_bracket() {
+        let (base_url, server) = stub_server("200 OK", "<<>^v>\n");
+        let client = Client::new(&base_url, "token");
+        let input = client.get_input(2015, 3).unwrap();
+        server.join().unwrap();
+
+        assert_eq!(input, "<<>^v>\n");
+        assert!(is_html_page("\n<html lang=\"en-us\">"));
+        assert!(!is_html_page("<v>^"));
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(
+            unlock_time(2024, 1).unwrap().to_rfc3339(),
+            "2024-12-01T05:00:00+00:00"
+        );
+        assert_eq!(
+            unlock_time(2025, 12).unwrap().to_rfc3339(),
+            "2025-12-12T05:00:00+00:00"
+        );
+        assert!(unlock_time(2024, 0).is_err());
+        assert!(unlock_time(2024, -3).is_err());
+        assert!(unlock_time(2024, 32).is_err());
+        assert!(unlock_time(2025, 20).is_err());
+    }
+
+    #[test]
+    fn test_days_in() {
+        assert_eq!(days_in(2015), 25);
+        assert_eq!(days_in(2024), 25);
+        assert_eq!(days_in(2025), 12);
+        assert!(check_day(2024, 25).is_ok());
+        assert!(check_day(2025, 12).is_ok());
+        assert!(check_day(2025, 13).is_err());
+    }
+
+    #[test]
+    fn test_ensure_unlocked() {
+        assert!(ensure_unlocked(2015, 1, false).is_ok());
+        assert!(ensure_unlocked(9999, 25, false).is_err());
+        assert!(ensure_unlocked(2015, 0, false).is_err());
+    }
+}
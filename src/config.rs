@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use once_cell::sync::Lazy;
+
+use serde::{Deserialize, Serialize};
+
+/// User configuration, stored as TOML in `~/.config/aoc-rust/config.toml`
+/// (or wherever `AOC_CONFIG` points).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Name of the profile whose session token is used for requests.
+    pub active_profile: Option<String>,
+
+    /// Where puzzle inputs and examples are stored, see [`input_dir`].
+    pub input_dir: Option<PathBuf>,
+
+    /// Named session token profiles, e.g. one per account.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    pub session_token: String,
+}
+
+impl Config {
+    pub fn path() -> PathBuf {
+        if let Ok(path) = env::var("AOC_CONFIG") {
+            return PathBuf::from(path);
+        }
+
+        let config_dir = env::var("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| {
+                PathBuf::from(env::var("HOME").unwrap_or_else(|_| ".".to_string())).join(".config")
+            });
+
+        config_dir.join("aoc-rust").join("config.toml")
+    }
+
+    /// Loads the config file, or an empty config if there isn't one yet.
+    pub fn load() -> Result<Config, Box<dyn Error>> {
+        let path = Config::path();
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let contents = fs::read_to_string(&path)?;
+        toml::from_str(&contents)
+            .map_err(|e| format!("Invalid config {}: {}", path.display(), e).into())
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        let path = Config::path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write_private(&path, &toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Returns the name and token of the profile to use: `AOC_PROFILE` if set,
+    /// otherwise the active profile.
+    pub fn current_profile(&self) -> Option<(&str, &Profile)> {
+        let name = env::var("AOC_PROFILE").ok();
+        let name = name.as_deref().or(self.active_profile.as_deref())?;
+        self.profiles
+            .get_key_value(name)
+            .map(|(name, profile)| (name.as_str(), profile))
+    }
+}
+
+/// Writes a file only its owner can read, since the config holds session
+/// tokens. An existing file has its permissions tightened too.
+fn write_private(path: &Path, contents: &str) -> io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+        // The mode only applies to new files
+        options.mode(0o600);
+        if path.exists() {
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+
+    options.open(path)?.write_all(contents.as_bytes())
+}
+
+/// Directory for cached responses: `AOC_CACHE_DIR`, or `aoc-rust` under
+/// `XDG_CACHE_HOME` (`~/.cache` by default).
+pub fn cache_dir() -> PathBuf {
+    if let Ok(dir) = env::var("AOC_CACHE_DIR") {
+        return PathBuf::from(dir);
+    }
+
+    let cache_home = env::var("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            PathBuf::from(env::var("HOME").unwrap_or_else(|_| ".".to_string())).join(".cache")
+        });
+
+    cache_home.join("aoc-rust")
+}
+
+/// The crate's folder, so that paths don't depend on where the binary or the
+/// tests are run from.
+pub fn crate_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+static INPUT_DIR: Lazy<Result<PathBuf, String>> = Lazy::new(|| {
+    let dir = match env::var("AOC_INPUT_DIR") {
+        Ok(dir) => Some(PathBuf::from(dir)),
+        Err(_) => Config::load().map_err(|e| e.to_string())?.input_dir,
+    };
+
+    Ok(crate_dir().join(dir.unwrap_or_else(|| PathBuf::from("src/aoc"))))
+});
+
+/// Root of the input store, laid out as `year_YYYY/day_DD/input/`:
+/// `AOC_INPUT_DIR`, or `input_dir` in the config file, or `src/aoc` next to the
+/// solvers by default. Relative paths are resolved from the crate's folder.
+///
+/// Fails if the config file can't be loaded, rather than quietly falling back
+/// to the default and reading or writing inputs in the wrong place.
+pub fn try_input_dir() -> Result<&'static Path, Box<dyn Error>> {
+    INPUT_DIR.as_deref().map_err(|e| e.clone().into())
+}
+
+/// Same as `try_input_dir`, for code that runs after `main` has checked it.
+/// Panics if the config file can't be loaded.
+pub fn input_dir() -> &'static Path {
+    try_input_dir().unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Returns the session token to use for requests. `SESSION_TOKEN` (e.g. from
+/// `.env`) takes precedence over the profiles in the config file.
+pub fn session_token() -> Result<String, Box<dyn Error>> {
+    session().map(|(_, token)| token)
+}
+
+/// Returns the name of the profile in use (`SESSION_TOKEN` when the token
+/// comes from the environment) and its session token.
+pub fn session() -> Result<(String, String), Box<dyn Error>> {
+    if let Ok(token) = env::var("SESSION_TOKEN") {
+        return Ok(("SESSION_TOKEN".to_string(), token));
+    }
+
+    let config = Config::load()?;
+    match config.current_profile() {
+        Some((name, profile)) => Ok((name.to_string(), profile.session_token.clone())),
+        None => Err(
+            "No session token configured: run `auth set <token>` or set SESSION_TOKEN in .env"
+                .into(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut config = Config::default();
+        config.profiles.insert(
+            "work".to_string(),
+            Profile {
+                session_token: "abc123".to_string(),
+            },
+        );
+        config.active_profile = Some("work".to_string());
+
+        let contents = toml::to_string_pretty(&config).unwrap();
+        assert!(contents.contains("active_profile = \"work\""));
+        assert!(contents.contains("[profiles.work]"));
+        assert_eq!(toml::from_str::<Config>(&contents).unwrap(), config);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join("aoc-rust-config-test.toml");
+        fs::write(&path, "old contents that are longer").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_private(&path, "new").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        fs::remove_file(&path).unwrap();
+        write_private(&path, "new").unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_empty_config() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config, Config::default());
+        assert!(config.current_profile().is_none());
+    }
+
+    #[test]
+    fn test_input_dir() {
+        let config: Config = toml::from_str("input_dir = \"/srv/aoc-inputs\"").unwrap();
+        assert_eq!(config.input_dir, Some(PathBuf::from("/srv/aoc-inputs")));
+
+        // A misspelled key is an error rather than silently using the default
+        assert!(toml::from_str::<Config>("input_dirs = \"/srv/aoc-inputs\"").is_err());
+        assert!(input_dir().is_absolute());
+    }
+}
@@ -0,0 +1,172 @@
+//! Where a solver's input comes from: the saved puzzle input, one of the
+//! example files next to it, any other file, or stdin.
+
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::config;
+use crate::params::Params;
+use crate::solvers;
+
+/// The text a solver is run on, with the puzzle parameters that go with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleInput {
+    pub text: String,
+    pub params: Params,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The downloaded input for the day.
+    Puzzle,
+    /// The Nth example of the day (`example.txt`, `example2.txt`, ...).
+    Example(usize),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses a `--input` value: a path, or `-` for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Where the input is read from, with `-` for stdin.
+    pub fn path(&self, year: i32, day: i32) -> String {
+        match self {
+            InputSource::Puzzle => input_path(year, day),
+            InputSource::Example(n) => example_path(year, day, *n),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => "-".to_string(),
+        }
+    }
+
+    /// Reads the input, with the day's default parameters for it.
+    pub fn read(&self, year: i32, day: i32) -> Result<PuzzleInput, Box<dyn Error>> {
+        let text = match self {
+            InputSource::Puzzle => {
+                let path = input_path(year, day);
+                fs::read_to_string(&path)
+                    .map_err(|e| format!("Could not read {} ({}), run download first", path, e))?
+            }
+            InputSource::Example(n) => {
+                let path = example_path(year, day, *n);
+                fs::read_to_string(&path)
+                    .map_err(|e| format!("Could not read example {} ({})", path, e))?
+            }
+            InputSource::File(path) => fs::read_to_string(path)
+                .map_err(|e| format!("Could not read {} ({})", path.display(), e))?,
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                text
+            }
+        };
+
+        // Other files get the real input's parameters, e.g. a teammate's input
+        let example = match self {
+            InputSource::Example(n) => Some(*n),
+            _ => None,
+        };
+
+        Ok(PuzzleInput {
+            text,
+            params: Params::defaults(solvers::params(year, day), example),
+        })
+    }
+}
+
+/// Folder with all the days' source, `src/aoc` in the crate.
+pub fn aoc_dir() -> PathBuf {
+    config::crate_dir().join("src").join("aoc")
+}
+
+/// Source folder of a day, with its `mod.rs`.
+pub fn day_folder(year: i32, day: i32) -> String {
+    aoc_dir()
+        .join(format!("year_{:04}", year))
+        .join(format!("day_{:02}", day))
+        .display()
+        .to_string()
+}
+
+/// Folder of a day's input and examples in the input store.
+pub fn input_folder(year: i32, day: i32) -> PathBuf {
+    config::input_dir()
+        .join(format!("year_{:04}", year))
+        .join(format!("day_{:02}", day))
+        .join("input")
+}
+
+pub fn input_path(year: i32, day: i32) -> String {
+    input_folder(year, day)
+        .join("input.txt")
+        .display()
+        .to_string()
+}
+
+/// Path of the Nth example: `example.txt` for the first, then `example2.txt`
+/// and so on.
+pub fn example_path(year: i32, day: i32, n: usize) -> String {
+    let name = match n {
+        1 => "example.txt".to_string(),
+        _ => format!("example{}.txt", n),
+    };
+    input_folder(year, day).join(name).display().to_string()
+}
+
+/// Whether the day has a downloaded input.
+pub fn has_input(year: i32, day: i32) -> bool {
+    Path::new(&input_path(year, day)).exists()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        let folder = config::input_dir().join("year_2024/day_03/input");
+        assert_eq!(
+            input_path(2024, 3),
+            folder.join("input.txt").display().to_string()
+        );
+        assert_eq!(
+            example_path(2024, 3, 1),
+            folder.join("example.txt").display().to_string()
+        );
+        assert_eq!(
+            example_path(2024, 3, 2),
+            folder.join("example2.txt").display().to_string()
+        );
+        assert!(day_folder(2024, 3).ends_with("src/aoc/year_2024/day_03"));
+    }
+
+    #[test]
+    fn test_read() {
+        let example = InputSource::Example(2).read(2024, 3).unwrap();
+        assert!(example.text.contains("don't()"));
+
+        let file = InputSource::from_arg(&example_path(2024, 3, 1));
+        let input = file.read(2024, 1).unwrap();
+        assert!(input.text.starts_with("xmul(2,4)"));
+
+        let example = InputSource::Example(1).read(2024, 14).unwrap();
+        assert_eq!(example.params.get::<i32>("width"), 11);
+        let file = InputSource::from_arg(&example_path(2024, 14, 1));
+        assert_eq!(file.read(2024, 14).unwrap().params.get::<i32>("width"), 101);
+
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(InputSource::Stdin.path(2024, 3), "-");
+        assert_eq!(
+            InputSource::Example(2).path(2024, 3),
+            example_path(2024, 3, 2)
+        );
+        assert!(InputSource::Example(9).read(2024, 3).is_err());
+    }
+}
@@ -0,0 +1,411 @@
+//! Private leaderboards: fetching (cached, since the site asks for at most one
+//! request every 15 minutes), parsing and rendering as a table, CSV or JSON.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+use crate::config;
+use crate::utils::csv;
+use crate::OutputFormat;
+
+/// How long a downloaded leaderboard is reused for.
+pub const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Deserialize)]
+struct RawLeaderboard {
+    event: String,
+    members: HashMap<String, RawMember>,
+}
+
+#[derive(Deserialize)]
+struct RawMember {
+    id: u64,
+    name: Option<String>,
+    stars: u32,
+    local_score: u32,
+    completion_day_level: HashMap<String, HashMap<String, RawStar>>,
+}
+
+#[derive(Deserialize)]
+struct RawStar {
+    get_star_ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Leaderboard {
+    pub event: String,
+    /// Members ordered by local score, highest first.
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub local_score: u32,
+    pub stars: u32,
+    /// Days with at least one star, in order.
+    pub days: Vec<DayStars>,
+}
+
+/// When a member got the stars for a day, as Unix timestamps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayStars {
+    pub day: u32,
+    pub part_1: Option<i64>,
+    pub part_2: Option<i64>,
+    /// Seconds between the part 1 and part 2 stars.
+    pub gap: Option<i64>,
+}
+
+impl Member {
+    fn day(&self, day: u32) -> Option<&DayStars> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+/// Parses the JSON returned by the site.
+pub fn parse(json: &str) -> Result<Leaderboard, Box<dyn Error>> {
+    let raw: RawLeaderboard =
+        serde_json::from_str(json).map_err(|e| format!("Invalid leaderboard JSON: {}", e))?;
+
+    let mut members: Vec<Member> = raw
+        .members
+        .into_values()
+        .map(|member| {
+            let mut days: Vec<DayStars> = member
+                .completion_day_level
+                .iter()
+                .filter_map(|(day, parts)| {
+                    let part_1 = parts.get("1").map(|star| star.get_star_ts);
+                    let part_2 = parts.get("2").map(|star| star.get_star_ts);
+                    Some(DayStars {
+                        day: day.parse().ok()?,
+                        part_1,
+                        part_2,
+                        gap: part_1.zip(part_2).map(|(one, two)| two - one),
+                    })
+                })
+                .collect();
+            days.sort_by_key(|d| d.day);
+
+            Member {
+                id: member.id,
+                name: member
+                    .name
+                    .unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+                local_score: member.local_score,
+                stars: member.stars,
+                days,
+            }
+        })
+        .collect();
+
+    members.sort_by(|a, b| {
+        (b.local_score, b.stars)
+            .cmp(&(a.local_score, a.stars))
+            .then(a.id.cmp(&b.id))
+    });
+
+    Ok(Leaderboard {
+        event: raw.event,
+        members,
+    })
+}
+
+/// Where a leaderboard is cached. Each profile gets its own copy, as what the
+/// site returns depends on the account asking.
+fn cache_path(year: i32, id: u64, profile: &str) -> PathBuf {
+    let profile: String = profile
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect();
+    config::cache_dir().join(format!("leaderboard_{}_{}_{}.json", year, id, profile))
+}
+
+/// Whether a cached copy written at `modified` can still be used at `now`.
+fn is_fresh(modified: SystemTime, now: SystemTime) -> bool {
+    now.duration_since(modified)
+        .is_ok_and(|age| age < CACHE_TTL)
+}
+
+/// Returns the leaderboard JSON, from the cache if it was downloaded less than
+/// `CACHE_TTL` ago and from the site otherwise.
+pub fn fetch(year: i32, id: u64) -> Result<String, Box<dyn Error>> {
+    let (profile, token) = config::session()?;
+    let path = cache_path(year, id, &profile);
+
+    if let Ok(modified) = fs::metadata(&path).and_then(|m| m.modified()) {
+        if is_fresh(modified, SystemTime::now()) {
+            let age = SystemTime::now().duration_since(modified)?;
+            eprintln!(
+                "Using leaderboard cached {}m ago ({})",
+                age.as_secs() / 60,
+                path.display()
+            );
+            return Ok(fs::read_to_string(&path)?);
+        }
+    }
+
+    let json = Client::with_session(&token).get_private_leaderboard(year, id)?;
+
+    // Only cache responses that parse, so a bad response isn't stuck for 15 minutes
+    parse(&json)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, &json)?;
+
+    Ok(json)
+}
+
+/// Fetches a private leaderboard and prints it in the requested format.
+pub fn show(year: i32, id: u64, format: OutputFormat) -> Result<(), Box<dyn Error>> {
+    let leaderboard = parse(&fetch(year, id)?)?;
+
+    let output = match format {
+        OutputFormat::Table => render_table(&leaderboard),
+        OutputFormat::Csv => render_csv(&leaderboard),
+        OutputFormat::Json => serde_json::to_string_pretty(&leaderboard)? + "\n",
+    };
+    print!("{}", output);
+
+    Ok(())
+}
+
+/// Renders the standings (`*` for both stars of a day, `+` for part 1 only),
+/// followed by the time each member took from part 1 to part 2.
+pub fn render_table(leaderboard: &Leaderboard) -> String {
+    let last_day = leaderboard
+        .members
+        .iter()
+        .flat_map(|m| m.days.iter().map(|d| d.day))
+        .max()
+        .unwrap_or(1);
+    let name_width = leaderboard
+        .members
+        .iter()
+        .map(|m| m.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Name".len());
+
+    let mut out = format!(
+        "{:>4}  {:>5}  {:>5}  {:<name_width$}",
+        "#", "Score", "Stars", "Name"
+    );
+    for day in 1..=last_day {
+        out.push_str(&format!("{:>3}", day));
+    }
+    out.push('\n');
+
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        out.push_str(&format!(
+            "{:>4}  {:>5}  {:>5}  {:<name_width$}",
+            rank + 1,
+            member.local_score,
+            member.stars,
+            member.name
+        ));
+        for day in 1..=last_day {
+            let cell = match member.day(day) {
+                Some(stars) if stars.part_2.is_some() => "*",
+                Some(_) => "+",
+                None => ".",
+            };
+            out.push_str(&format!("{:>3}", cell));
+        }
+        out.push('\n');
+    }
+
+    let gap_days: Vec<u32> = (1..=last_day)
+        .filter(|&day| {
+            leaderboard
+                .members
+                .iter()
+                .any(|m| m.day(day).is_some_and(|d| d.gap.is_some()))
+        })
+        .collect();
+    if gap_days.is_empty() {
+        return out;
+    }
+
+    out.push_str(&format!("\nPart 1 to part 2\n{:<name_width$}", "Name"));
+    for day in &gap_days {
+        out.push_str(&format!("{:>7}", day));
+    }
+    out.push('\n');
+
+    for member in &leaderboard.members {
+        out.push_str(&format!("{:<name_width$}", member.name));
+        for &day in &gap_days {
+            let gap = member
+                .day(day)
+                .and_then(|d| d.gap)
+                .map_or("-".to_string(), format_gap);
+            out.push_str(&format!("{:>7}", gap));
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Renders one row per member and day with stars. Members without any stars
+/// get a single row with the day columns left empty.
+pub fn render_csv(leaderboard: &Leaderboard) -> String {
+    fn field<T: ToString>(value: Option<T>) -> String {
+        value.map_or(String::new(), |v| v.to_string())
+    }
+
+    let mut out =
+        "rank,id,name,local_score,stars,day,part_1_ts,part_2_ts,gap_seconds\n".to_string();
+
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        let prefix = format!(
+            "{},{},{},{},{}",
+            rank + 1,
+            member.id,
+            csv::escape(&member.name),
+            member.local_score,
+            member.stars
+        );
+
+        if member.days.is_empty() {
+            out.push_str(&format!("{},,,,\n", prefix));
+        }
+        for day in &member.days {
+            out.push_str(&format!(
+                "{},{},{},{},{}\n",
+                prefix,
+                day.day,
+                field(day.part_1),
+                field(day.part_2),
+                field(day.gap)
+            ));
+        }
+    }
+
+    out
+}
+
+/// Formats a number of seconds compactly: `7:05`, `2h06m` or `1d03h`.
+pub fn format_gap(seconds: i64) -> String {
+    if seconds < 3600 {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    } else if seconds < 86400 {
+        format!("{}h{:02}m", seconds / 3600, seconds % 3600 / 60)
+    } else {
+        format!("{}d{:02}h", seconds / 86400, seconds % 86400 / 3600)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("fixtures/leaderboard.json");
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = parse(FIXTURE).unwrap();
+
+        assert_eq!(leaderboard.event, "2024");
+        let names: Vec<&str> = leaderboard
+            .members
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Alice", "(anonymous user #222)", "Bob"]);
+
+        let alice = &leaderboard.members[0];
+        assert_eq!(alice.days.len(), 3);
+        assert_eq!(
+            alice.days[0],
+            DayStars {
+                day: 1,
+                part_1: Some(1733029500),
+                part_2: Some(1733029925),
+                gap: Some(425),
+            }
+        );
+        assert_eq!(alice.days[2].part_2, None);
+        assert_eq!(alice.days[2].gap, None);
+
+        assert!(parse("<html></html>").is_err());
+    }
+
+    #[test]
+    fn test_render_table() {
+        let table = render_table(&parse(FIXTURE).unwrap());
+
+        assert_eq!(
+            table,
+            "   #  Score  Stars  Name                   1  2  3
+   1     14      5  Alice                  *  *  +
+   2      5      2  (anonymous user #222)  *  .  .
+   3      0      0  Bob                    .  .  .
+
+Part 1 to part 2
+Name                       1      2
+Alice                   7:05  2h06m
+(anonymous user #222)  1d03h      -
+Bob                        -      -
+"
+        );
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let csv = render_csv(&parse(FIXTURE).unwrap());
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[1], "1,111,Alice,14,5,1,1733029500,1733029925,425");
+        assert_eq!(lines[3], "1,111,Alice,14,5,3,1733217000,,");
+        assert_eq!(lines[5], "3,333,Bob,0,0,,,,");
+    }
+
+    #[test]
+    fn test_json_export() {
+        let json = serde_json::to_value(parse(FIXTURE).unwrap()).unwrap();
+
+        assert_eq!(json["members"][0]["name"], "Alice");
+        assert_eq!(json["members"][0]["days"][1]["gap"], 7605);
+        assert!(json["members"][0]["days"][2]["part_2"].is_null());
+    }
+
+    #[test]
+    fn test_format_gap() {
+        assert_eq!(format_gap(45), "0:45");
+        assert_eq!(format_gap(425), "7:05");
+        assert_eq!(format_gap(7605), "2h06m");
+        assert_eq!(format_gap(100000), "1d03h");
+    }
+
+    #[test]
+    fn test_cache_path() {
+        let work = cache_path(2024, 111, "work");
+        assert_ne!(work, cache_path(2024, 111, "home"));
+        assert!(work.ends_with("leaderboard_2024_111_work.json"));
+        assert!(cache_path(2024, 111, "../me").ends_with("leaderboard_2024_111____me.json"));
+    }
+
+    #[test]
+    fn test_is_fresh() {
+        let now = SystemTime::now();
+
+        assert!(is_fresh(now - Duration::from_secs(60), now));
+        assert!(!is_fresh(now - CACHE_TTL, now));
+        assert!(!is_fresh(now - Duration::from_secs(3600), now));
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::answer::Answer;
+use crate::input;
+use crate::Part;
+
+const HEADER: &str = "# Accepted answers, one per line: <day> <part> <answer>\n";
+
+/// The accepted answers for every day and part of a single year, stored in
+/// `src/aoc/year_YYYY/answers.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ledger {
+    year: i32,
+    answers: BTreeMap<(i32, Part), Answer>,
+}
+
+impl Ledger {
+    pub fn path(year: i32) -> PathBuf {
+        input::aoc_dir()
+            .join(format!("year_{:04}", year))
+            .join("answers.txt")
+    }
+
+    /// Loads the ledger for a year, or an empty one if it has not been created yet.
+    pub fn load(year: i32) -> Result<Ledger, Box<dyn Error>> {
+        let path = Ledger::path(year);
+        if !path.exists() {
+            return Ok(Ledger {
+                year,
+                answers: BTreeMap::new(),
+            });
+        }
+
+        let contents = fs::read_to_string(&path)?;
+        Ledger::parse(year, &contents)
+            .map_err(|e| format!("Invalid ledger {}: {}", path.display(), e).into())
+    }
+
+    pub fn parse(year: i32, contents: &str) -> Result<Ledger, String> {
+        let mut answers = BTreeMap::new();
+
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let (day, part, answer) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(day), Some(part), Some(answer)) => (day, part, answer),
+                _ => return Err(format!("line {}: expected <day> <part> <answer>", i + 1)),
+            };
+
+            let day = day
+                .parse::<i32>()
+                .map_err(|_| format!("line {}: invalid day '{}'", i + 1, day))?;
+            let part = match part {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(format!("line {}: invalid part '{}'", i + 1, part)),
+            };
+
+            answers.insert((day, part), Answer::parse(answer));
+        }
+
+        Ok(Ledger { year, answers })
+    }
+
+    pub fn get(&self, day: i32, part: Part) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn record(&mut self, day: i32, part: Part, answer: Answer) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        fs::write(Ledger::path(self.year), self.to_string())?;
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Ledger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", HEADER)?;
+        for ((day, part), answer) in &self.answers {
+            writeln!(f, "{} {} {}", day, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let contents = "# comment\n\n24 2 cdj,dhm,gfm\n1 1 1830467\n";
+        let ledger = Ledger::parse(2024, contents).unwrap();
+
+        assert_eq!(ledger.get(1, Part::One), Some(&Answer::Int(1830467)));
+        assert_eq!(
+            ledger.get(24, Part::Two),
+            Some(&Answer::from("cdj,dhm,gfm"))
+        );
+        assert_eq!(ledger.get(1, Part::Two), None);
+
+        assert_eq!(
+            ledger.to_string(),
+            format!("{}1 1 1830467\n24 2 cdj,dhm,gfm\n", HEADER)
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Ledger::parse(2024, "1 3 42").is_err());
+        assert!(Ledger::parse(2024, "x 1 42").is_err());
+        assert!(Ledger::parse(2024, "1 1").is_err());
+    }
+}
@@ -1,30 +1,61 @@
-use std::env;
-
 use chrono::Datelike;
 use clap::Parser;
 use dotenv::dotenv;
 
+mod answer;
 mod aoc;
+mod auth;
+mod bench;
+mod client;
+mod config;
+mod input;
+mod leaderboard;
+mod ledger;
+mod params;
+mod puzzle;
+mod report;
+mod run;
+mod scaffold;
+mod submit;
 pub mod utils;
+mod verify;
 
+use answer::Answer;
 use clap::Subcommand;
 use clap::ValueEnum;
-use solvers::SOLVERS;
+use client::Client;
+use input::InputSource;
+use ledger::Ledger;
+use solvers::SolveOutcome;
+use std::path::{Path, PathBuf};
 
 mod solvers;
+mod stats;
+mod status;
 
-const BASE_URL: &str = "https://adventofcode.com";
-const USER_AGENT: &str = concat!(
-    "github.com/jmugliston/advent-of-code-rust v",
-    env!("CARGO_PKG_VERSION")
-);
-
-#[derive(Debug, Clone, Copy, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, ValueEnum)]
 pub enum Part {
     One,
     Two,
 }
 
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// How commands that export data print it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Csv,
+    Json,
+}
+
 #[derive(Parser, Debug)]
 #[command(name = "CLI", version, about = "AoC CLI Tool")]
 struct Cli {
@@ -38,9 +69,41 @@ struct Cli {
     long_about = None,
 )]
 enum MainCommand {
-    Init(CommandArgs),
-    Download(CommandArgs),
+    Init(InitArgs),
+    Download(DownloadArgs),
+    Describe(CommandArgs),
     Solve(SolveArgs),
+    Submit(SubmitArgs),
+    Verify(VerifyArgs),
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Leaderboard(LeaderboardArgs),
+    Stats(StatsArgs),
+    Status(StatusArgs),
+    Auth {
+        #[command(subcommand)]
+        command: AuthCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum AuthCommand {
+    /// Show the configured profiles and check the active session token
+    Status,
+    /// Save a session token to a profile
+    Set {
+        /// Session cookie value from the site
+        token: String,
+
+        /// Profile name (optional, defaults to `default`)
+        #[arg(short, long)]
+        profile: Option<String>,
+    },
+    /// Switch the active profile
+    Use {
+        /// Profile name
+        profile: String,
+    },
 }
 
 #[derive(Parser, Debug)]
@@ -50,8 +113,55 @@ struct CommandArgs {
     year: i32,
 
     /// Day (required)
-    #[arg(short, long, default_value_t = if chrono::Local::now().month() == 12 { chrono::Local::now().day() as i32 } else { 1 })]
+    #[arg(short, long, default_value_t = if chrono::Local::now().month() == 12 { chrono::Local::now().day() as i32 } else { 1 }, value_parser = clap::value_parser!(i32).range(1..=25))]
+    day: i32,
+}
+
+#[derive(Parser, Debug)]
+struct InitArgs {
+    /// Year (required)
+    #[arg(short, long, default_value_t = chrono::Local::now().year())]
+    year: i32,
+
+    /// Day (required)
+    #[arg(short, long, default_value_t = if chrono::Local::now().month() == 12 { chrono::Local::now().day() as i32 } else { 1 }, value_parser = clap::value_parser!(i32).range(1..=25))]
+    day: i32,
+
+    /// Template to scaffold the day from: a folder in `template/`, such as
+    /// `grid`, `numbers`, `graph` or `sections`
+    #[arg(short, long, default_value = "default")]
+    template: String,
+
+    /// Download the input again even if it is already saved
+    #[arg(short, long, default_value_t = false)]
+    force: bool,
+
+    /// Wait for the puzzle to unlock instead of failing
+    #[arg(short, long, default_value_t = false)]
+    wait: bool,
+
+    /// Print the files that would be created without changing anything
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+#[derive(Parser, Debug)]
+struct DownloadArgs {
+    /// Year (required)
+    #[arg(short, long, default_value_t = chrono::Local::now().year())]
+    year: i32,
+
+    /// Day (required)
+    #[arg(short, long, default_value_t = if chrono::Local::now().month() == 12 { chrono::Local::now().day() as i32 } else { 1 }, value_parser = clap::value_parser!(i32).range(1..=25))]
     day: i32,
+
+    /// Download the input again even if it is already saved
+    #[arg(short, long, default_value_t = false)]
+    force: bool,
+
+    /// Wait for the puzzle to unlock instead of failing
+    #[arg(short, long, default_value_t = false)]
+    wait: bool,
 }
 
 #[derive(Parser, Debug)]
@@ -61,16 +171,149 @@ struct SolveArgs {
     year: i32,
 
     /// Day (required)
-    #[arg(short, long, default_value_t = if chrono::Local::now().month() == 12 { chrono::Local::now().day() as i32 } else { 1 })]
+    #[arg(short, long, default_value_t = if chrono::Local::now().month() == 12 { chrono::Local::now().day() as i32 } else { 1 }, value_parser = clap::value_parser!(i32).range(1..=25))]
+    day: i32,
+
+    /// Part (optional, defaults to both parts)
+    #[arg(short, long, value_parser = clap::value_parser!(i8).range(1..=2))]
+    part: Option<i8>,
+
+    /// Use an example as input: `example.txt`, or `exampleN.txt` for N > 1
+    #[arg(short, long, num_args = 0..=1, default_missing_value = "1")]
+    example: Option<usize>,
+
+    /// Read the input from a file instead, or from stdin with `-`
+    #[arg(short, long, conflicts_with = "example")]
+    input: Option<String>,
+
+    /// Override a puzzle parameter, e.g. `--param width=50` (repeatable)
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_override)]
+    params: Vec<(String, i64)>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+}
+
+#[derive(Parser, Debug)]
+struct SubmitArgs {
+    /// Year (required)
+    #[arg(short, long, default_value_t = chrono::Local::now().year())]
+    year: i32,
+
+    /// Day (required)
+    #[arg(short, long, default_value_t = if chrono::Local::now().month() == 12 { chrono::Local::now().day() as i32 } else { 1 }, value_parser = clap::value_parser!(i32).range(1..=25))]
     day: i32,
 
-    /// Part (optional, defaults to 1)
+    /// Part (required)
     #[arg(short, long, value_parser = clap::value_parser!(i8).range(1..=2))]
     part: i8,
 
-    /// Use the example file as input (defaults to false)
+    /// Answer to submit (optional, defaults to running the solver)
+    #[arg(short, long)]
+    answer: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+struct VerifyArgs {
+    /// Year (optional, defaults to all years)
+    #[arg(short, long)]
+    year: Option<i32>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+}
+
+#[derive(Parser, Debug)]
+struct RunArgs {
+    /// Year (required)
+    #[arg(short, long, default_value_t = chrono::Local::now().year())]
+    year: i32,
+
+    /// Days to run, e.g. `1-10` or `1,3,5` (optional, defaults to all days)
+    #[arg(long)]
+    days: Option<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+}
+
+#[derive(Parser, Debug)]
+struct BenchArgs {
+    /// Year (required)
+    #[arg(short, long, default_value_t = chrono::Local::now().year())]
+    year: i32,
+
+    /// Day (required)
+    #[arg(short, long, default_value_t = if chrono::Local::now().month() == 12 { chrono::Local::now().day() as i32 } else { 1 }, value_parser = clap::value_parser!(i32).range(1..=25))]
+    day: i32,
+
+    /// Part (optional, defaults to both parts)
+    #[arg(short, long, value_parser = clap::value_parser!(i8).range(1..=2))]
+    part: Option<i8>,
+
+    /// Number of timed iterations
+    #[arg(short = 'n', long, default_value_t = 10)]
+    iterations: usize,
+
+    /// Number of untimed warm-up iterations
+    #[arg(short, long, default_value_t = 3)]
+    warmup: usize,
+
+    /// File to save the results to
+    #[arg(long, default_value = "bench/latest.json")]
+    save: std::path::PathBuf,
+
+    /// Baseline file to compare the results against
+    #[arg(long, default_value = "bench/baseline.json")]
+    baseline: std::path::PathBuf,
+
+    /// Also store the results as the new baseline
+    #[arg(long, default_value_t = false)]
+    save_baseline: bool,
+
+    /// Percentage slowdown of the median that counts as a regression
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Parser, Debug)]
+struct LeaderboardArgs {
+    /// Private leaderboard ID, as in its URL (required)
+    #[arg(long)]
+    id: u64,
+
+    /// Year (optional, defaults to the latest event)
+    #[arg(short, long, default_value_t = if chrono::Local::now().month() == 12 { chrono::Local::now().year() } else { chrono::Local::now().year() - 1 })]
+    year: i32,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+}
+
+#[derive(Parser, Debug)]
+struct StatsArgs {
+    /// Year (optional, defaults to the latest event)
+    #[arg(short, long, default_value_t = if chrono::Local::now().month() == 12 { chrono::Local::now().year() } else { chrono::Local::now().year() - 1 })]
+    year: i32,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+}
+
+#[derive(Parser, Debug)]
+struct StatusArgs {
+    /// Year (optional, defaults to all years)
+    #[arg(short, long)]
+    year: Option<i32>,
+
+    /// Also run the example tests and show whether they pass
     #[arg(short, long, default_value_t = false)]
-    example: bool,
+    tests: bool,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -78,193 +321,375 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let cli = Cli::parse();
 
+    // Fail early on a broken config instead of using the wrong input store
+    if !matches!(cli.command, MainCommand::Auth { .. }) {
+        config::try_input_dir()?;
+    }
+
+    // Reject days an event doesn't have before doing anything, e.g. waiting
+    let puzzle = match &cli.command {
+        MainCommand::Init(args) => Some((args.year, args.day)),
+        MainCommand::Download(args) => Some((args.year, args.day)),
+        MainCommand::Describe(args) => Some((args.year, args.day)),
+        MainCommand::Solve(args) => Some((args.year, args.day)),
+        MainCommand::Submit(args) => Some((args.year, args.day)),
+        MainCommand::Bench(args) => Some((args.year, args.day)),
+        _ => None,
+    };
+    if let Some((year, day)) = puzzle {
+        client::check_day(year, day)?;
+    }
+
     match cli.command {
-        MainCommand::Init(args) => {
-            return init(args.year, args.day);
-        }
-        MainCommand::Download(args) => {
-            return download(args.year, args.day);
-        }
+        MainCommand::Init(args) => init(
+            args.year,
+            args.day,
+            &args.template,
+            args.force,
+            args.wait,
+            args.dry_run,
+        ),
+        MainCommand::Download(args) => download(args.year, args.day, args.force, args.wait),
+        MainCommand::Describe(args) => describe(args.year, args.day).map(|_| ()),
         MainCommand::Solve(args) => {
-            return solve(
+            let source = match (args.example, args.input) {
+                (Some(n), _) => InputSource::Example(n),
+                (None, Some(path)) => InputSource::from_arg(&path),
+                (None, None) => InputSource::Puzzle,
+            };
+            let parts = match args.part {
+                Some(part) => vec![part_from_arg(part)?],
+                None => vec![Part::One, Part::Two],
+            };
+            solve(
                 args.year,
                 args.day,
-                match args.part {
-                    1 => Part::One,
-                    2 => Part::Two,
-                    _ => return Err(format!("Invalid part").into()),
-                },
-                args.example,
-            );
+                &parts,
+                &source,
+                &args.params,
+                args.format,
+            )
         }
-    }
-}
-
-fn init(year: i32, day: i32) -> Result<(), Box<dyn std::error::Error>> {
-    let year_folder = format!("src/aoc/year_{:04}", year);
-    if !std::path::Path::new(&year_folder).exists() {
-        std::fs::create_dir_all(&year_folder)?;
-        let mod_rs_path = format!("{}/mod.rs", year_folder);
-        if !std::path::Path::new(&mod_rs_path).exists() {
-            std::fs::write(&mod_rs_path, "")?;
+        MainCommand::Submit(args) => {
+            submit(args.year, args.day, part_from_arg(args.part)?, args.answer)
         }
-        // Update the mod file in the year folder to import the new day
-        let aoc_mod_rs_path = "src/aoc/mod.rs";
-        let mod_entry = format!("pub mod year_{:04};\n", year);
-
-        let mut mod_rs_content = if std::path::Path::new(&aoc_mod_rs_path).exists() {
-            std::fs::read_to_string(&aoc_mod_rs_path)?
-        } else {
-            String::new()
-        };
-
-        if !mod_rs_content.contains(&mod_entry) {
-            mod_rs_content.push_str(&mod_entry);
-            std::fs::write(&aoc_mod_rs_path, mod_rs_content)?;
+        MainCommand::Verify(args) => verify::verify(args.year, args.format),
+        MainCommand::Run(args) => run::run(args.year, args.days.as_deref(), args.format),
+        MainCommand::Bench(args) => {
+            let parts = match args.part {
+                Some(part) => vec![part_from_arg(part)?],
+                None => vec![Part::One, Part::Two],
+            };
+            let options = bench::BenchOptions {
+                iterations: args.iterations,
+                warmup: args.warmup,
+                save: &args.save,
+                baseline: &args.baseline,
+                save_baseline: args.save_baseline,
+                threshold: args.threshold,
+            };
+            bench::bench(args.year, args.day, &parts, &options)
         }
+        MainCommand::Leaderboard(args) => leaderboard::show(args.year, args.id, args.format),
+        MainCommand::Stats(args) => stats::stats(args.year, args.format),
+        MainCommand::Status(args) => status::status(args.year, args.tests),
+        MainCommand::Auth { command } => match command {
+            AuthCommand::Status => auth::status(),
+            AuthCommand::Set { token, profile } => auth::set(&token, profile.as_deref()),
+            AuthCommand::Use { profile } => auth::use_profile(&profile),
+        },
     }
+}
 
-    let src_folder = "template/day_00";
-    let dest_folder = format!("src/aoc/year_{:04}/day_{:02}", year, day);
-
-    if !std::path::Path::new(&dest_folder).exists() {
-        std::fs::create_dir_all(&dest_folder)?;
+fn part_from_arg(part: i8) -> Result<Part, Box<dyn std::error::Error>> {
+    match part {
+        1 => Ok(Part::One),
+        2 => Ok(Part::Two),
+        _ => Err("Invalid part".into()),
     }
+}
 
-    let mut options = fs_extra::dir::CopyOptions::new();
-    options.overwrite = false;
-    options.content_only = true;
-    fs_extra::dir::copy(src_folder, &dest_folder, &options)?;
-
-    // Find and replace year_0000 with the year and day_00 with the day
-    let mod_rs_path = format!("{}/mod.rs", dest_folder);
-    if std::path::Path::new(&mod_rs_path).exists() {
-        let content = std::fs::read_to_string(&mod_rs_path)?;
-        let content = content
-            .replace("year_0000", &format!("year_{:04}", year))
-            .replace("day_00", &format!("day_{:02}", day));
-        std::fs::write(&mod_rs_path, content)?;
+/// Scaffolds a day from a template, then downloads its input and description.
+/// A day that already has a `mod.rs` is left as it is. If any step fails, the
+/// day folder is put back the way it was.
+fn init(
+    year: i32,
+    day: i32,
+    template: &str,
+    force: bool,
+    wait: bool,
+    dry_run: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let template_dir = config::crate_dir().join("template");
+    let templates = scaffold::templates(&template_dir)?;
+    if !templates.iter().any(|name| name == template) {
+        return Err(format!(
+            "Unknown template '{}' (available: {})",
+            template,
+            templates.join(", ")
+        )
+        .into());
     }
 
-    // Update the mod file in the year folder to import the new day
-    let year_mod_rs_path = format!("src/aoc/year_{:04}/mod.rs", year);
-    let mod_entry = format!("pub mod day_{:02};\n", day);
-
-    let mut mod_rs_content = if std::path::Path::new(&year_mod_rs_path).exists() {
-        std::fs::read_to_string(&year_mod_rs_path)?
-    } else {
-        String::new()
-    };
+    // New days are picked up by build.rs, so all that's needed is the folder
+    let day_folder = PathBuf::from(input::day_folder(year, day));
+    let input_folder = input::input_folder(year, day);
+    let changes = scaffold::plan(
+        &template_dir.join(template),
+        &day_folder,
+        &input_folder,
+        year,
+        day,
+    )?;
+    if changes.is_empty() {
+        println!(
+            "{} already exists, leaving it as is",
+            day_folder.join("mod.rs").display()
+        );
+    }
 
-    if !mod_rs_content.contains(&mod_entry) {
-        mod_rs_content.push_str(&mod_entry);
-        std::fs::write(&year_mod_rs_path, mod_rs_content)?;
+    if dry_run {
+        for change in &changes {
+            println!("Would {}", change);
+        }
+        let input_path = input::input_path(year, day);
+        if force || !input::has_input(year, day) {
+            println!("Would download the input to {}", input_path);
+        }
+        println!(
+            "Would save {}/README.md and any missing examples from the puzzle page",
+            day_folder.display()
+        );
+        return Ok(());
     }
 
-    let mut years = Vec::new();
-    let mut days = std::collections::HashMap::new();
-
-    for entry in std::fs::read_dir("src/aoc")? {
-        let entry = entry?;
-        let file_name = entry.file_name().to_string_lossy().to_string();
-        if let Some(year_str) = file_name.strip_prefix("year_") {
-            if let Ok(year_num) = year_str.parse::<i32>() {
-                let mut day_list = Vec::new();
-                for day_entry in std::fs::read_dir(entry.path())? {
-                    let day_entry = day_entry?;
-                    let day_name = day_entry.file_name().to_string_lossy().to_string();
-                    if let Some(day_str) = day_name.strip_prefix("day_") {
-                        if let Ok(day_num) = day_str.parse::<i32>() {
-                            day_list.push(day_num);
-                        }
-                    }
-                }
-                if !day_list.is_empty() {
-                    years.push(year_num);
-                    days.insert(year_num, day_list);
-                }
+    // The input store may be outside the day folder
+    let snapshots = [
+        scaffold::Snapshot::take(&input_folder)?,
+        scaffold::Snapshot::take(&day_folder)?,
+    ];
+    let result = scaffold::apply(&changes)
+        .map_err(|e| e.into())
+        .and_then(|_| download(year, day, force, wait))
+        .and_then(|_| describe(year, day))
+        .and_then(|html| {
+            // Expected answers only go into a mod.rs scaffolded just now
+            if changes.is_empty() {
+                return Ok(());
             }
+            fill_expected_answers(&day_folder.join("mod.rs"), &html)
+        });
+
+    if let Err(e) = result {
+        for snapshot in &snapshots {
+            snapshot.rollback()?;
         }
+        return Err(format!("{} (undid the changes to {})", e, day_folder.display()).into());
     }
+    Ok(())
+}
 
-    // Update the solvers map
-    let solvers_rs_path = "src/solvers.rs";
-    let solvers_rs_content = std::fs::read_to_string(solvers_rs_path)?;
-    let start_marker = "// --- AUTO GENERATED MAP START ---";
-
-    let start_idx = solvers_rs_content
-        .find(start_marker)
-        .ok_or("Start marker not found in solvers.rs")?;
-
-    let mut map_entries = String::new();
-    let mut years_sorted = years.clone();
-    years_sorted.sort();
-    for year in years_sorted {
-        let mut day_list = days.get(&year).cloned().unwrap_or_default();
-        day_list.sort();
-        for day in day_list {
-            map_entries.push_str(&format!(
-                "    m.insert(({}, {}), aoc::year_{:04}::day_{:02}::main as SolveFn);\n",
-                year, day, year, day
-            ));
+/// Replaces the `0 /* expected part_N */` placeholders in a freshly scaffolded
+/// day with the answers highlighted in the puzzle's examples. Only answers for
+/// `example.txt` are used, since that's what the template's tests read.
+fn fill_expected_answers(mod_rs_path: &Path, html: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut content = std::fs::read_to_string(mod_rs_path)?;
+    let first_example = puzzle::example_inputs(html).into_iter().next();
+
+    for (i, example) in puzzle::part_examples(html).into_iter().enumerate() {
+        let placeholder = format!("0 /* expected part_{} */", i + 1);
+        let answer = example
+            .filter(|example| Some(&example.input) == first_example.as_ref())
+            .and_then(|example| example.answer);
+
+        if let Some(answer) = answer {
+            // Integer literals default to i32, so larger answers need a suffix
+            let literal = match answer.parse::<i128>() {
+                Ok(n) if i32::try_from(n).is_ok() => answer,
+                Ok(n) if i64::try_from(n).is_ok() => format!("{}_i64", answer),
+                Ok(_) => format!("{}_i128", answer),
+                Err(_) => format!("{:?}", answer),
+            };
+            println!("Guessed part {} example answer: {}", i + 1, literal);
+            content = content.replace(&placeholder, &literal);
         }
     }
 
-    let new_map = format!(
-        "{start}// --- AUTO GENERATED MAP START ---
-pub static SOLVERS: Lazy<HashMap<(i32, i32), SolveFn>> = Lazy::new(|| {{
-    let mut m = HashMap::new();
-{entries}    
-    return m;
-}});
-// --- AUTO GENERATED MAP END ---
-",
-        start = &solvers_rs_content[..start_idx],
-        entries = map_entries,
-    );
+    for part in 1..=2 {
+        content = content.replace(&format!("0 /* expected part_{} */", part), "0");
+    }
+
+    std::fs::write(mod_rs_path, content)?;
+    Ok(())
+}
+
+/// Downloads the puzzle input, reusing the saved copy unless `force` is set.
+fn download(
+    year: i32,
+    day: i32,
+    force: bool,
+    wait: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let input_path = input::input_path(year, day);
+
+    let cached = std::fs::read_to_string(&input_path).unwrap_or_default();
+    if !force && !cached.is_empty() {
+        println!(
+            "Using saved input {} (use --force to download it again)",
+            input_path
+        );
+        return Ok(());
+    }
+
+    client::ensure_unlocked(year, day, wait)?;
+
+    let client = Client::from_env()?;
 
-    std::fs::write(solvers_rs_path, new_map)?;
+    let resp_text = client.get_input(year, day)?;
 
-    return download(year, day);
+    std::fs::create_dir_all(input::input_folder(year, day))?;
+    std::fs::write(&input_path, &resp_text)?;
+
+    return Ok(());
 }
 
-fn download(year: i32, day: i32) -> Result<(), Box<dyn std::error::Error>> {
-    let url = format!("{}/{}/day/{}/input", BASE_URL, year, day);
+/// Saves the puzzle description as Markdown in the day folder, along with any
+/// example inputs that aren't there yet. Running it again once part 1 is solved
+/// picks up the part 2 description and examples too. Returns the puzzle page.
+fn describe(year: i32, day: i32) -> Result<String, Box<dyn std::error::Error>> {
+    let day_folder = input::day_folder(year, day);
+    if !std::path::Path::new(&day_folder).exists() {
+        return Err(format!("{} does not exist, run init first", day_folder).into());
+    }
 
-    let session_token = env::var("SESSION_TOKEN")?;
+    client::ensure_unlocked(year, day, false)?;
 
-    let client = reqwest::blocking::Client::new();
+    let client = Client::from_env()?;
 
-    let resp = client
-        .get(&url)
-        .header(reqwest::header::USER_AGENT, USER_AGENT)
-        .header("Cookie", format!("session={}", session_token))
-        .send()?;
+    let html = client.get_puzzle(year, day)?;
+    let markdown = puzzle::to_markdown(&html, client.base_url())
+        .ok_or("No puzzle description found on the page")?;
 
-    if !resp.status().is_success() {
-        let status = resp.status();
-        let body = resp
-            .text()
-            .unwrap_or_else(|_| "<failed to read body>".to_string());
-        eprintln!(
-            "Error: received status {} from server. Body: {}",
-            status, body
-        );
-        return Err(format!("Failed to download input: status {}", status).into());
+    std::fs::write(format!("{}/README.md", day_folder), markdown)?;
+
+    std::fs::create_dir_all(input::input_folder(year, day))?;
+    for (i, example) in puzzle::example_inputs(&html).iter().enumerate() {
+        let example_path = input::example_path(year, day, i + 1);
+
+        // Never overwrite an example that has already been filled in
+        let existing = std::fs::read_to_string(&example_path).unwrap_or_default();
+        if existing.trim().is_empty() {
+            std::fs::write(&example_path, example)?;
+            println!("Saved {}", example_path);
+        }
     }
 
-    let resp_text = resp.text()?;
+    Ok(html)
+}
 
-    let input_path = format!("src/aoc/year_{:04}/day_{:02}/input/input.txt", year, day);
-    std::fs::write(&input_path, &resp_text)?;
+/// Prints the answer for a single part, or the answers and timings for both
+/// parts (including the parse stage, for days that have one). With JSON or
+/// CSV output, prints a record per part instead.
+fn solve(
+    year: i32,
+    day: i32,
+    parts: &[Part],
+    source: &InputSource,
+    overrides: &[(String, i64)],
+    format: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut input = source.read(year, day)?;
+    for (name, value) in overrides {
+        input.params.set(name, *value)?;
+    }
 
-    return Ok(());
+    if format != OutputFormat::Table {
+        let run = solvers::run(year, day, parts, &input);
+        let records: Vec<report::Record> = run
+            .parts
+            .iter()
+            .map(|(part, outcome, elapsed)| {
+                report::Record::new(year, day, *part, &source.path(year, day), outcome, *elapsed)
+                    .with_parse(run.parse)
+            })
+            .collect();
+        print!("{}", report::render(&records, format)?);
+
+        let failed = records
+            .iter()
+            .filter(|record| record.status != report::Status::Ok)
+            .count();
+        if failed > 0 {
+            return Err(format!("{} part(s) failed", failed).into());
+        }
+        return Ok(());
+    }
+
+    if let [part] = parts {
+        let answer = solvers::solve(year, day, *part, &input)?;
+        println!("{}", answer);
+        return Ok(());
+    }
+
+    let run = solvers::run(year, day, parts, &input);
+    if let Some(parse) = run.parse {
+        println!("Parse:  {}", run::format_duration(parse));
+    }
+
+    let mut failed = 0;
+    for (part, outcome, elapsed) in &run.parts {
+        match outcome {
+            SolveOutcome::Answer(answer) => println!(
+                "Part {}: {} ({})",
+                part,
+                answer,
+                run::format_duration(*elapsed)
+            ),
+            SolveOutcome::Error(e) => {
+                failed += 1;
+                println!("Part {}: error: {}", part, e);
+            }
+            SolveOutcome::Panic(message) => {
+                failed += 1;
+                println!("Part {}: panic: {}", part, message);
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} part(s) failed", failed).into());
+    }
+    Ok(())
 }
 
-fn solve(year: i32, day: i32, part: Part, example: bool) -> Result<(), Box<dyn std::error::Error>> {
-    if let Some(solver) = SOLVERS.get(&(year, day)) {
-        solver(part, example)
-    } else {
-        Err(format!("Day {} not implemented for year {}.", day, year).into())
+fn submit(
+    year: i32,
+    day: i32,
+    part: Part,
+    answer: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let input = InputSource::Puzzle.read(year, day)?;
+            solvers::solve(year, day, part, &input)?.to_string()
+        }
+    };
+
+    let client = Client::from_env()?;
+
+    println!(
+        "Submitting {} for {} day {} part {}...",
+        answer, year, day, part
+    );
+    let outcome = client.submit_answer(year, day, part, &answer)?;
+    println!("{}", outcome);
+
+    if outcome.is_correct() {
+        let mut ledger = Ledger::load(year)?;
+        ledger.record(day, part, Answer::parse(&answer));
+        ledger.save()?;
     }
+
+    Ok(())
 }
@@ -0,0 +1,149 @@
+//! Named puzzle parameters, for constants that the puzzle text gives separately
+//! for the examples and the real input (grid sizes, step counts, thresholds).
+//!
+//! A day declares them with a `PARAMS` constant, which `build.rs` picks up:
+//!
+//! ```ignore
+//! pub const PARAMS: &[Param] = &[
+//!     Param { name: "width", input: 101, examples: &[11] },
+//! ];
+//! ```
+
+use std::collections::BTreeMap;
+
+/// A parameter declared by a day, with its value for the real input and for
+/// each example in order (`example.txt`, `example2.txt`, ...). Examples past
+/// the end of the list use the last example value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub input: i64,
+    pub examples: &'static [i64],
+}
+
+/// The parameter values a solver is run with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, i64>,
+}
+
+impl Params {
+    /// The declared defaults for the real input (`example` is `None`) or for
+    /// the given example.
+    pub fn defaults(declared: &[Param], example: Option<usize>) -> Self {
+        let values = declared
+            .iter()
+            .map(|param| {
+                let value = match example {
+                    Some(n) if !param.examples.is_empty() => {
+                        param.examples[(n.max(1) - 1).min(param.examples.len() - 1)]
+                    }
+                    _ => param.input,
+                };
+                (param.name.to_string(), value)
+            })
+            .collect();
+
+        Params { values }
+    }
+
+    /// Returns a parameter's value. Panics if the day didn't declare it, or if
+    /// the value doesn't fit in `T`.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> T {
+        let value = *self
+            .values
+            .get(name)
+            .unwrap_or_else(|| panic!("Parameter '{}' is not declared in PARAMS", name));
+        T::try_from(value)
+            .unwrap_or_else(|_| panic!("Parameter {}={} is out of range", name, value))
+    }
+
+    /// Overrides a declared parameter.
+    pub fn set(&mut self, name: &str, value: i64) -> Result<(), String> {
+        if let Some(existing) = self.values.get_mut(name) {
+            *existing = value;
+            return Ok(());
+        }
+
+        if self.values.is_empty() {
+            return Err(format!("Unknown parameter '{}': this day has none", name));
+        }
+        let names: Vec<&str> = self.values.keys().map(|k| k.as_str()).collect();
+        Err(format!(
+            "Unknown parameter '{}' (expected one of: {})",
+            name,
+            names.join(", ")
+        ))
+    }
+}
+
+/// Parses a `--param` value such as `width=50`.
+pub fn parse_override(s: &str) -> Result<(String, i64), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("Expected NAME=VALUE, got '{}'", s))?;
+    let value = value
+        .trim()
+        .parse::<i64>()
+        .map_err(|_| format!("Invalid value for '{}': '{}'", name.trim(), value.trim()))?;
+    Ok((name.trim().to_string(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[
+        Param {
+            name: "width",
+            input: 101,
+            examples: &[11, 5],
+        },
+        Param {
+            name: "steps",
+            input: 100,
+            examples: &[],
+        },
+    ];
+
+    #[test]
+    fn test_defaults() {
+        let input = Params::defaults(PARAMS, None);
+        assert_eq!(input.get::<i32>("width"), 101);
+        assert_eq!(input.get::<usize>("steps"), 100);
+
+        let example = Params::defaults(PARAMS, Some(1));
+        assert_eq!(example.get::<i32>("width"), 11);
+        assert_eq!(example.get::<i32>("steps"), 100);
+
+        assert_eq!(Params::defaults(PARAMS, Some(2)).get::<i32>("width"), 5);
+        assert_eq!(Params::defaults(PARAMS, Some(3)).get::<i32>("width"), 5);
+    }
+
+    #[test]
+    fn test_set() {
+        let mut params = Params::defaults(PARAMS, None);
+        params.set("width", 50).unwrap();
+        assert_eq!(params.get::<i32>("width"), 50);
+
+        let err = params.set("height", 3).unwrap_err();
+        assert!(err.contains("expected one of: steps, width"));
+        assert!(Params::default().set("width", 1).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_get_out_of_range() {
+        let mut params = Params::defaults(PARAMS, None);
+        params.set("width", -1).unwrap();
+        params.get::<usize>("width");
+    }
+
+    #[test]
+    fn test_parse_override() {
+        assert_eq!(parse_override("width=50"), Ok(("width".to_string(), 50)));
+        assert_eq!(parse_override(" n = -3"), Ok(("n".to_string(), -3)));
+        assert!(parse_override("width").is_err());
+        assert!(parse_override("width=wide").is_err());
+    }
+}
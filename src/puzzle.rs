@@ -0,0 +1,385 @@
+//! Parsing of puzzle pages: pulls the `<article class="day-desc">` sections out
+//! of the HTML and converts them to Markdown.
+
+/// Returns the inner HTML of each `<article class="day-desc">` on the page, in
+/// order. Part 2 only appears once part 1 has been solved.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article class=\"day-desc\">") {
+        let inner = &rest[start + "<article class=\"day-desc\">".len()..];
+        let end = inner.find("</article>").unwrap_or(inner.len());
+        articles.push(&inner[..end]);
+        rest = &inner[end..];
+    }
+
+    articles
+}
+
+/// Converts the puzzle description on a page to Markdown. Relative links are
+/// resolved against `base_url`.
+pub fn to_markdown(html: &str, base_url: &str) -> Option<String> {
+    let articles = articles(html);
+    if articles.is_empty() {
+        return None;
+    }
+
+    let markdown = articles
+        .iter()
+        .map(|article| html_to_markdown(article, base_url))
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    Some(format!("{}\n", markdown))
+}
+
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                let Some(end) = rest.find('>') else {
+                    // An unterminated tag isn't markup, keep it as text
+                    tokens.push(Token::Text(rest));
+                    break;
+                };
+                let tag = &rest[1..end];
+                if let Some(name) = tag.strip_prefix('/') {
+                    tokens.push(Token::Close(name.trim()));
+                } else {
+                    let tag = tag.trim_end_matches('/');
+                    let (name, attrs) = tag.split_once(' ').unwrap_or((tag, ""));
+                    tokens.push(Token::Open(name, attrs));
+                }
+                rest = &rest[end + 1..];
+            }
+            Some(i) => {
+                tokens.push(Token::Text(&rest[..i]));
+                rest = &rest[i..];
+            }
+            None => {
+                tokens.push(Token::Text(rest));
+                rest = "";
+            }
+        }
+    }
+
+    tokens
+}
+
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let key = format!("{}=\"", name);
+    let start = attrs.find(&key)? + key.len();
+    let end = attrs[start..].find('"')? + start;
+    Some(&attrs[start..end])
+}
+
+pub fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn escape_markdown(c: char) -> String {
+    if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+        format!("\\{}", c)
+    } else {
+        c.to_string()
+    }
+}
+
+/// Converts the subset of HTML used in puzzle descriptions to Markdown.
+fn html_to_markdown(html: &str, base_url: &str) -> String {
+    let mut out = String::new();
+    // Stack of (buffer, href) for links being built
+    let mut links: Vec<(String, String)> = Vec::new();
+    let mut pre = false;
+    let mut code: Option<(String, bool)> = None;
+
+    fn push(out: &mut String, links: &mut [(String, String)], text: &str) {
+        let target = match links.last_mut() {
+            Some((buffer, _)) => buffer,
+            None => out,
+        };
+        // Don't start a line (or follow another space) with a space
+        let text = if target.is_empty() || target.ends_with(['\n', ' ']) {
+            text.trim_start_matches(' ')
+        } else {
+            text
+        };
+        target.push_str(text);
+    }
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                if pre {
+                    out.push_str(&text);
+                } else if let Some((buffer, _)) = code.as_mut() {
+                    buffer.push_str(&text);
+                } else {
+                    let mut collapsed = String::new();
+                    for c in text.chars() {
+                        if c.is_whitespace() {
+                            if !collapsed.ends_with(' ') {
+                                collapsed.push(' ');
+                            }
+                        } else {
+                            collapsed.push_str(&escape_markdown(c));
+                        }
+                    }
+                    push(&mut out, &mut links, &collapsed);
+                }
+            }
+            Token::Open(name, attrs) => match name {
+                "h2" => out.push_str("## "),
+                "p" => {}
+                "ul" => {}
+                "li" => out.push_str("- "),
+                "pre" => {
+                    pre = true;
+                    out.push_str("```\n");
+                }
+                "code" if !pre => code = Some((String::new(), false)),
+                "em" => match code.as_mut() {
+                    Some((_, emphasised)) => *emphasised = true,
+                    None if !pre => push(&mut out, &mut links, "**"),
+                    None => {}
+                },
+                "a" => {
+                    let href = attribute(attrs, "href").unwrap_or("");
+                    let href = if href.starts_with('/') {
+                        format!("{}{}", base_url, href)
+                    } else {
+                        href.to_string()
+                    };
+                    links.push((String::new(), href));
+                }
+                "br" => push(&mut out, &mut links, "  \n"),
+                _ => {}
+            },
+            Token::Close(name) => match name {
+                "h2" | "p" => {
+                    let trimmed = out.trim_end_matches(' ').len();
+                    out.truncate(trimmed);
+                    out.push_str("\n\n");
+                }
+                "li" => {
+                    let trimmed = out.trim_end().len();
+                    out.truncate(trimmed);
+                    out.push('\n');
+                }
+                "ul" => out.push('\n'),
+                "pre" => {
+                    pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                "code" if !pre => {
+                    if let Some((content, emphasised)) = code.take() {
+                        let ticks = if content.contains('`') { "``" } else { "`" };
+                        let span = format!("{}{}{}", ticks, content, ticks);
+                        let span = if emphasised {
+                            format!("**{}**", span)
+                        } else {
+                            span
+                        };
+                        push(&mut out, &mut links, &span);
+                    }
+                }
+                "em" if code.is_none() && !pre => push(&mut out, &mut links, "**"),
+                "a" => {
+                    if let Some((text, href)) = links.pop() {
+                        let link = format!("[{}]({})", text.trim(), href);
+                        push(&mut out, &mut links, &link);
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+
+    out.trim().to_string()
+}
+
+/// The example given for one part of a puzzle, with the answer the description
+/// highlights for it (if one could be found).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answer: Option<String>,
+}
+
+/// Returns every `<pre><code>` block that directly follows a paragraph saying
+/// "For example", in order of appearance and without duplicates.
+pub fn example_inputs(html: &str) -> Vec<String> {
+    let mut inputs: Vec<String> = Vec::new();
+    for article in articles(html) {
+        for input in article_examples(article) {
+            if !inputs.contains(&input) {
+                inputs.push(input);
+            }
+        }
+    }
+    inputs
+}
+
+/// Returns the first example of each part described on the page, along with a
+/// guess at its answer: the last `<code><em>` in that part's description.
+pub fn part_examples(html: &str) -> Vec<Option<Example>> {
+    articles(html)
+        .into_iter()
+        .map(|article| {
+            article_examples(article)
+                .into_iter()
+                .next()
+                .map(|input| Example {
+                    input,
+                    answer: highlighted_answer(article),
+                })
+        })
+        .collect()
+}
+
+fn article_examples(article: &str) -> Vec<String> {
+    let mut examples = Vec::new();
+    let mut rest = article;
+
+    while let Some(start) = rest.find("<pre><code>") {
+        let before = &rest[..start];
+        let paragraph = before.rfind("<p>").map_or(before, |p| &before[p..]);
+
+        let block = &rest[start + "<pre><code>".len()..];
+        let end = block.find("</code></pre>").unwrap_or(block.len());
+
+        if strip_tags(paragraph).to_lowercase().contains("for example") {
+            examples.push(decode_entities(&strip_tags(&block[..end])));
+        }
+
+        rest = &block[end..];
+    }
+
+    examples
+}
+
+fn highlighted_answer(article: &str) -> Option<String> {
+    ["<code><em>", "<em><code>"]
+        .iter()
+        .filter_map(|open| {
+            let start = article.rfind(open)? + open.len();
+            let end = article[start..].find('<')? + start;
+            Some((start, decode_entities(&article[start..end])))
+        })
+        .max_by_key(|(start, _)| *start)
+        .map(|(_, answer)| answer)
+}
+
+pub fn strip_tags(html: &str) -> String {
+    tokenize(html)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_1: &str = include_str!("fixtures/puzzle_part_1.html");
+    const PART_2: &str = include_str!("fixtures/puzzle_part_2.html");
+
+    #[test]
+    fn test_articles() {
+        assert_eq!(articles(PART_1).len(), 1);
+        assert_eq!(articles(PART_2).len(), 2);
+        assert!(articles("<html><main></main></html>").is_empty());
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let markdown = to_markdown(PART_1, "https://adventofcode.com").unwrap();
+        assert_eq!(markdown, include_str!("fixtures/puzzle_part_1.md"));
+    }
+
+    #[test]
+    fn test_to_markdown_adds_part_2() {
+        let markdown = to_markdown(PART_2, "https://adventofcode.com").unwrap();
+        assert!(markdown.starts_with(include_str!("fixtures/puzzle_part_1.md").trim_end()));
+        assert!(markdown.contains("## --- Part Two ---"));
+        assert!(markdown.ends_with("What is the **total sum** of the calibration values?\n"));
+    }
+
+    #[test]
+    fn test_example_inputs() {
+        let part_1 = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+        let part_2 = "two1nine\neightwothree\nabcone2threexyz\n7pqrstsixteen\n";
+
+        assert_eq!(example_inputs(PART_1), vec![part_1]);
+        assert_eq!(example_inputs(PART_2), vec![part_1, part_2]);
+    }
+
+    #[test]
+    fn test_part_examples() {
+        let examples = part_examples(PART_2);
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].as_ref().unwrap().answer.as_deref(), Some("142"));
+        assert_eq!(examples[1].as_ref().unwrap().answer.as_deref(), Some("201"));
+        assert!(examples[1].as_ref().unwrap().input.starts_with("two1nine"));
+    }
+
+    #[test]
+    fn test_examples_need_for_example() {
+        let html = "<article class=\"day-desc\"><p>The map looks like this:</p>\
+            <pre><code>#.#\n</code></pre><p>For example, if the map is:</p>\
+            <pre><code>&lt;<em>v</em>&gt;\n</code></pre><p>That gives <em><code>7</code></em>.</p></article>";
+
+        assert_eq!(
+            part_examples(html),
+            vec![Some(Example {
+                input: "<v>\n".to_string(),
+                answer: Some("7".to_string()),
+            })]
+        );
+    }
+
+    #[test]
+    fn test_inline_formatting() {
+        let html = "<article class=\"day-desc\"><p>Use <code>a &lt; b</code>, \
+            <em>not</em> <code><em>42</em></code>; see <a href=\"/2024/about\">the <em>about</em> page</a>.</p>\
+            <ul><li>one <code>x*y</code></li><li>two</li></ul></article>";
+
+        assert_eq!(
+            to_markdown(html, "https://adventofcode.com").unwrap(),
+            "Use `a < b`, **not** **`42`**; see [the **about** page](https://adventofcode.com/2024/about).\n\n- one `x*y`\n- two\n"
+        );
+    }
+
+    #[test]
+    fn test_unterminated_tag() {
+        assert_eq!(
+            html_to_markdown("<p>a < b <", ""),
+            html_to_markdown("<p>a &lt; b &lt;", "")
+        );
+        assert_eq!(html_to_markdown("<", ""), "<");
+    }
+}
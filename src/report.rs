@@ -0,0 +1,221 @@
+//! Machine-readable results for the commands that run solvers (`solve`, `run`
+//! and `verify`), one record per part.
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::solvers::SolveOutcome;
+use crate::utils::csv;
+use crate::{OutputFormat, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Error,
+    Panic,
+    /// The solver wasn't run, e.g. `verify` on a day without an input.
+    Skipped,
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::Panic => "panic",
+            Status::Skipped => "skipped",
+        }
+    }
+}
+
+/// The result of running one part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: i32,
+    pub day: i32,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub elapsed_ns: u64,
+    /// Time spent in the day's parse stage, shared by both parts.
+    pub parse_ns: Option<u64>,
+    pub input: String,
+    /// The recorded answer and whether it matched (`pass`, `fail` or
+    /// `missing`), only set by `verify`.
+    pub expected: Option<String>,
+    pub verdict: Option<&'static str>,
+}
+
+impl Record {
+    pub fn new(
+        year: i32,
+        day: i32,
+        part: Part,
+        input: &str,
+        outcome: &SolveOutcome,
+        elapsed: Duration,
+    ) -> Self {
+        let (status, answer, error) = match outcome {
+            SolveOutcome::Answer(answer) => (Status::Ok, Some(answer.to_string()), None),
+            SolveOutcome::Error(e) => (Status::Error, None, Some(e.clone())),
+            SolveOutcome::Panic(message) => (Status::Panic, None, Some(message.clone())),
+        };
+
+        Record {
+            year,
+            day,
+            part: part_number(part),
+            status,
+            answer,
+            error,
+            elapsed_ns: elapsed.as_nanos() as u64,
+            parse_ns: None,
+            input: input.to_string(),
+            expected: None,
+            verdict: None,
+        }
+    }
+
+    /// A part whose solver couldn't be run at all, e.g. because its input is
+    /// missing. `error` says why, if it's a failure.
+    pub fn skipped(year: i32, day: i32, part: Part, input: &str, error: Option<String>) -> Self {
+        let status = match error {
+            Some(_) => Status::Error,
+            None => Status::Skipped,
+        };
+
+        Record {
+            year,
+            day,
+            part: part_number(part),
+            status,
+            answer: None,
+            error,
+            elapsed_ns: 0,
+            parse_ns: None,
+            input: input.to_string(),
+            expected: None,
+            verdict: None,
+        }
+    }
+
+    pub fn with_parse(mut self, parse: Option<Duration>) -> Self {
+        self.parse_ns = parse.map(|d| d.as_nanos() as u64);
+        self
+    }
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// Renders records as JSON or CSV. Tables are left to each command, since
+/// they lay their results out differently.
+pub fn render(records: &[Record], format: OutputFormat) -> Result<String, serde_json::Error> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(records)? + "\n"),
+        _ => Ok(render_csv(records)),
+    }
+}
+
+fn render_csv(records: &[Record]) -> String {
+    fn field<T: ToString>(value: &Option<T>) -> String {
+        value
+            .as_ref()
+            .map_or(String::new(), |v| csv::escape(&v.to_string()))
+    }
+
+    let mut out = "year,day,part,status,answer,error,elapsed_ns,parse_ns,input,expected,verdict\n"
+        .to_string();
+    for record in records {
+        out.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{},{}\n",
+            record.year,
+            record.day,
+            record.part,
+            record.status.label(),
+            field(&record.answer),
+            field(&record.error),
+            record.elapsed_ns,
+            field(&record.parse_ns),
+            csv::escape(&record.input),
+            field(&record.expected),
+            field(&record.verdict)
+        ));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record::new(
+                2024,
+                3,
+                Part::One,
+                "src/aoc/year_2024/day_03/input/input.txt",
+                &SolveOutcome::Answer(Answer::Int(161)),
+                Duration::from_micros(12),
+            )
+            .with_parse(Some(Duration::from_nanos(800))),
+            Record::new(
+                2024,
+                3,
+                Part::Two,
+                "-",
+                &SolveOutcome::Panic("index out of bounds".to_string()),
+                Duration::from_nanos(5),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&records(), OutputFormat::Json).unwrap()).unwrap();
+
+        assert_eq!(json[0]["part"], 1);
+        assert_eq!(json[0]["status"], "ok");
+        assert_eq!(json[0]["answer"], "161");
+        assert_eq!(json[0]["elapsed_ns"], 12_000);
+        assert_eq!(json[0]["parse_ns"], 800);
+        assert_eq!(json[1]["status"], "panic");
+        assert_eq!(json[1]["answer"], serde_json::Value::Null);
+        assert_eq!(json[1]["error"], "index out of bounds");
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let csv = render(&records(), OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[1],
+            "2024,3,1,ok,161,,12000,800,src/aoc/year_2024/day_03/input/input.txt,,"
+        );
+        assert_eq!(lines[2], "2024,3,2,panic,,index out of bounds,5,,-,,");
+    }
+
+    #[test]
+    fn test_skipped() {
+        let record = Record::skipped(2024, 3, Part::Two, "input.txt", None);
+        assert_eq!(record.status, Status::Skipped);
+        assert_eq!(record.error, None);
+
+        let record = Record::skipped(2024, 3, Part::Two, "input.txt", Some("no".to_string()));
+        assert_eq!(record.status, Status::Error);
+        assert_eq!(record.part, 2);
+    }
+}
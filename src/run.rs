@@ -0,0 +1,169 @@
+use std::error::Error;
+use std::time::Duration;
+
+use crate::input::InputSource;
+use crate::report::{self, Record, Status};
+use crate::solvers;
+use crate::{OutputFormat, Part};
+
+/// Runs both parts of every registered day in a year (optionally restricted to
+/// a selection of days) and prints a table of answers and timings, or a record
+/// per part. Days with a parse stage get a separate row for it in the table.
+pub fn run(year: i32, days: Option<&str>, format: OutputFormat) -> Result<(), Box<dyn Error>> {
+    let selection = match days {
+        Some(days) => Some(parse_days(days)?),
+        None => None,
+    };
+
+    let days: Vec<i32> = solvers::days(Some(year))
+        .into_iter()
+        .map(|(_, day)| day)
+        .filter(|day| selection.as_ref().is_none_or(|s| s.contains(day)))
+        .collect();
+
+    if days.is_empty() {
+        return Err(format!("No solvers registered for the requested days of {}", year).into());
+    }
+
+    let mut records = Vec::new();
+    for &day in &days {
+        let path = InputSource::Puzzle.path(year, day);
+        let input = match InputSource::Puzzle.read(year, day) {
+            Ok(input) => input,
+            Err(e) => {
+                for part in [Part::One, Part::Two] {
+                    records.push(Record::skipped(year, day, part, &path, Some(e.to_string())));
+                }
+                continue;
+            }
+        };
+
+        let run = solvers::run(year, day, &[Part::One, Part::Two], &input);
+        for (part, outcome, elapsed) in &run.parts {
+            records.push(
+                Record::new(year, day, *part, &path, outcome, *elapsed).with_parse(run.parse),
+            );
+        }
+    }
+
+    if format != OutputFormat::Table {
+        print!("{}", report::render(&records, format)?);
+        return Ok(());
+    }
+
+    let mut rows = Vec::new();
+    for record in &records {
+        if let (1, Some(parse)) = (record.part, record.parse_ns) {
+            rows.push((
+                record.day,
+                "parse".to_string(),
+                String::new(),
+                Duration::from_nanos(parse),
+            ));
+        }
+        let answer = match (&record.answer, &record.error) {
+            (Some(answer), _) => answer.clone(),
+            (None, Some(e)) if record.status == Status::Panic => format!("panic: {}", e),
+            (None, e) => format!("error: {}", e.as_deref().unwrap_or_default()),
+        };
+        rows.push((
+            record.day,
+            record.part.to_string(),
+            answer,
+            Duration::from_nanos(record.elapsed_ns),
+        ));
+    }
+
+    let width = rows
+        .iter()
+        .map(|(_, _, answer, _)| answer.len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!(
+        "{:<5}{:<6}{:<width$}  {:>10}",
+        "Day", "Part", "Answer", "Time"
+    );
+    for (day, part, answer, elapsed) in &rows {
+        println!(
+            "{:<5}{:<6}{:<width$}  {:>10}",
+            format!("{:02}", day),
+            part,
+            answer,
+            format_duration(*elapsed)
+        );
+    }
+
+    let total: Duration = rows.iter().map(|(_, _, _, elapsed)| *elapsed).sum();
+    println!(
+        "{:<11}{:<width$}  {:>10}",
+        "Total",
+        "",
+        format_duration(total)
+    );
+
+    Ok(())
+}
+
+/// Parses a day selection such as `5`, `1-10` or `1-3,7,20-25`.
+pub fn parse_days(s: &str) -> Result<Vec<i32>, String> {
+    let mut days = Vec::new();
+
+    for item in s.split(',').map(|item| item.trim()) {
+        let parse = |d: &str| {
+            d.trim()
+                .parse::<i32>()
+                .map_err(|_| format!("Invalid day '{}' in '{}'", d, s))
+        };
+
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+                if start > end {
+                    return Err(format!("Invalid day range '{}'", item));
+                }
+                days.extend(start..=end);
+            }
+            None => days.push(parse(item)?),
+        }
+    }
+
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("5"), Ok(vec![5]));
+        assert_eq!(parse_days("1-4"), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(parse_days("20-22, 3,21"), Ok(vec![3, 20, 21, 22]));
+        assert!(parse_days("4-1").is_err());
+        assert!(parse_days("one").is_err());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2346)), "2.35s");
+    }
+}
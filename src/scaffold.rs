@@ -0,0 +1,300 @@
+//! Scaffolding a day for `init`. The files are planned up front so that
+//! `--dry-run` can list them, and a snapshot of the day folder is taken first
+//! so that a failure in a later step (such as the download) can be rolled back.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A change `init` makes to the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    CreateDir(PathBuf),
+    WriteFile(PathBuf, String),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::CreateDir(path) => write!(f, "create {}/", path.display()),
+            Change::WriteFile(path, contents) => {
+                write!(
+                    f,
+                    "write  {} ({} lines)",
+                    path.display(),
+                    contents.lines().count()
+                )
+            }
+        }
+    }
+}
+
+/// Returns the names of the templates in `root`: its folders, sorted.
+pub fn templates(root: &Path) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(root)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Plans the files for a new day from a template, with `year_0000` and `day_00`
+/// in its source files replaced by the year and day. The template's `input`
+/// folder goes to the day's folder in the input store. Files that already
+/// exist are left alone, and a day that already has a `mod.rs` is never
+/// touched, so nothing is planned for it.
+pub fn plan(
+    template: &Path,
+    day_folder: &Path,
+    input_folder: &Path,
+    year: i32,
+    day: i32,
+) -> Result<Vec<Change>, Box<dyn Error>> {
+    if day_folder.join("mod.rs").exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut changes = Vec::new();
+    if !day_folder.exists() {
+        changes.push(Change::CreateDir(day_folder.to_path_buf()));
+    }
+    plan_dir(
+        template,
+        day_folder,
+        Some(input_folder),
+        year,
+        day,
+        &mut changes,
+    )?;
+    Ok(changes)
+}
+
+fn plan_dir(
+    from: &Path,
+    to: &Path,
+    input_folder: Option<&Path>,
+    year: i32,
+    day: i32,
+    changes: &mut Vec<Change>,
+) -> Result<(), Box<dyn Error>> {
+    let mut entries = fs::read_dir(from)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let target = match input_folder {
+            Some(input_folder) if entry.file_name() == "input" => input_folder.to_path_buf(),
+            _ => to.join(entry.file_name()),
+        };
+        if entry.file_type()?.is_dir() {
+            if !target.exists() {
+                changes.push(Change::CreateDir(target.clone()));
+            }
+            plan_dir(&entry.path(), &target, None, year, day, changes)?;
+        } else if !target.exists() {
+            let mut contents = fs::read_to_string(entry.path())?;
+            if entry.path().extension().is_some_and(|ext| ext == "rs") {
+                contents = contents
+                    .replace("year_0000", &format!("year_{:04}", year))
+                    .replace("day_00", &format!("day_{:02}", day));
+            }
+            changes.push(Change::WriteFile(target, contents));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn apply(changes: &[Change]) -> io::Result<()> {
+    for change in changes {
+        match change {
+            Change::CreateDir(path) => fs::create_dir_all(path)?,
+            Change::WriteFile(path, contents) => fs::write(path, contents)?,
+        }
+    }
+    Ok(())
+}
+
+/// The contents of a folder (and which of its parents existed) before `init`
+/// changed it.
+#[derive(Debug)]
+pub struct Snapshot {
+    root: PathBuf,
+    missing_parents: Vec<PathBuf>,
+    dirs: BTreeSet<PathBuf>,
+    files: BTreeMap<PathBuf, Vec<u8>>,
+}
+
+impl Snapshot {
+    pub fn take(root: &Path) -> io::Result<Self> {
+        let missing_parents = root
+            .ancestors()
+            .skip(1)
+            .take_while(|parent| !parent.as_os_str().is_empty() && !parent.exists())
+            .map(Path::to_path_buf)
+            .collect();
+
+        let (mut dirs, mut files) = (BTreeSet::new(), BTreeMap::new());
+        if root.exists() {
+            walk(root, &mut dirs, &mut files)?;
+        }
+
+        Ok(Snapshot {
+            root: root.to_path_buf(),
+            missing_parents,
+            dirs,
+            files,
+        })
+    }
+
+    /// Puts the folder back as it was: files and folders that weren't there are
+    /// removed, and files that were changed get their old contents back.
+    pub fn rollback(&self) -> io::Result<()> {
+        if self.root.exists() {
+            let (mut dirs, mut files) = (BTreeSet::new(), BTreeMap::new());
+            walk(&self.root, &mut dirs, &mut files)?;
+
+            for (path, contents) in &files {
+                match self.files.get(path) {
+                    None => fs::remove_file(path)?,
+                    Some(old) if old != contents => fs::write(path, old)?,
+                    Some(_) => {}
+                }
+            }
+            // Children sort after their parents, so they are removed first
+            let new_dirs: Vec<&PathBuf> = dirs.difference(&self.dirs).collect();
+            for dir in new_dirs.into_iter().rev() {
+                fs::remove_dir(dir)?;
+            }
+        }
+
+        for parent in &self.missing_parents {
+            if fs::read_dir(parent).is_ok_and(|mut entries| entries.next().is_none()) {
+                fs::remove_dir(parent)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Collects a folder (itself included) and everything in it.
+fn walk(
+    dir: &Path,
+    dirs: &mut BTreeSet<PathBuf>,
+    files: &mut BTreeMap<PathBuf, Vec<u8>>,
+) -> io::Result<()> {
+    dirs.insert(dir.to_path_buf());
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            walk(&entry.path(), dirs, files)?;
+        } else {
+            files.insert(entry.path(), fs::read(entry.path())?);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-rust-scaffold-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn template(name: &str) -> PathBuf {
+        crate::config::crate_dir().join("template").join(name)
+    }
+
+    #[test]
+    fn test_templates() {
+        let names = templates(&template("")).unwrap();
+        for name in ["default", "graph", "grid", "numbers", "sections"] {
+            assert!(names.contains(&name.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_plan() {
+        let root = scratch("plan");
+        let day_folder = root.join("src").join("year_2025").join("day_03");
+        let input_folder = root.join("inputs").join("year_2025").join("day_03");
+
+        let changes = plan(&template("grid"), &day_folder, &input_folder, 2025, 3).unwrap();
+        assert_eq!(
+            changes[..3],
+            [
+                Change::CreateDir(day_folder.clone()),
+                Change::CreateDir(input_folder.clone()),
+                Change::WriteFile(input_folder.join("example.txt"), String::new()),
+            ]
+        );
+        match &changes[3] {
+            Change::WriteFile(path, contents) => {
+                assert_eq!(path, &day_folder.join("mod.rs"));
+                assert!(contents.contains("\"year_2025/day_03/input/example.txt\""));
+                assert!(contents.contains("parse_string_grid"));
+            }
+            change => panic!("unexpected {:?}", change),
+        }
+
+        // A day with a mod.rs is left alone, even if files are missing
+        apply(&changes).unwrap();
+        fs::remove_file(input_folder.join("example.txt")).unwrap();
+        assert_eq!(
+            plan(&template("grid"), &day_folder, &input_folder, 2025, 3).unwrap(),
+            []
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_rollback() {
+        let root = scratch("rollback");
+        let existing = root.join("day_01");
+        fs::create_dir_all(existing.join("input")).unwrap();
+        fs::write(existing.join("mod.rs"), "// mine").unwrap();
+        fs::write(existing.join("input").join("example.txt"), "").unwrap();
+
+        let snapshot = Snapshot::take(&existing).unwrap();
+        fs::write(existing.join("input").join("example.txt"), "1 2 3").unwrap();
+        fs::write(existing.join("input").join("input.txt"), "4 5 6").unwrap();
+        fs::create_dir_all(existing.join("notes").join("old")).unwrap();
+        snapshot.rollback().unwrap();
+
+        assert_eq!(
+            fs::read_to_string(existing.join("mod.rs")).unwrap(),
+            "// mine"
+        );
+        assert_eq!(
+            fs::read_to_string(existing.join("input").join("example.txt")).unwrap(),
+            ""
+        );
+        assert!(!existing.join("input").join("input.txt").exists());
+        assert!(!existing.join("notes").exists());
+
+        // New folders are removed along with any new parent folders
+        let new = root.join("year_2026").join("day_01");
+        let snapshot = Snapshot::take(&new).unwrap();
+        let changes = plan(&template("default"), &new, &new.join("input"), 2026, 1).unwrap();
+        apply(&changes).unwrap();
+        assert!(new.join("mod.rs").exists());
+        snapshot.rollback().unwrap();
+        assert!(!root.join("year_2026").exists());
+        assert!(root.exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
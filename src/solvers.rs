@@ -1,49 +1,239 @@
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
+use std::error::Error;
+use std::panic;
+use std::time::{Duration, Instant};
 
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
 use crate::Part;
 
 use crate::aoc;
 
-type SolveFn = fn(Part, bool) -> Result<(), Box<dyn std::error::Error>>;
-
-// --- AUTO GENERATED MAP START ---
-pub static SOLVERS: Lazy<HashMap<(i32, i32), SolveFn>> = Lazy::new(|| {
-    let mut m = HashMap::new();
-    m.insert((2024, 1), aoc::year_2024::day_01::main as SolveFn);
-    m.insert((2024, 2), aoc::year_2024::day_02::main as SolveFn);
-    m.insert((2024, 3), aoc::year_2024::day_03::main as SolveFn);
-    m.insert((2024, 4), aoc::year_2024::day_04::main as SolveFn);
-    m.insert((2024, 5), aoc::year_2024::day_05::main as SolveFn);
-    m.insert((2024, 6), aoc::year_2024::day_06::main as SolveFn);
-    m.insert((2024, 7), aoc::year_2024::day_07::main as SolveFn);
-    m.insert((2024, 8), aoc::year_2024::day_08::main as SolveFn);
-    m.insert((2024, 9), aoc::year_2024::day_09::main as SolveFn);
-    m.insert((2024, 10), aoc::year_2024::day_10::main as SolveFn);
-    m.insert((2024, 11), aoc::year_2024::day_11::main as SolveFn);
-    m.insert((2024, 12), aoc::year_2024::day_12::main as SolveFn);
-    m.insert((2024, 13), aoc::year_2024::day_13::main as SolveFn);
-    m.insert((2024, 14), aoc::year_2024::day_14::main as SolveFn);
-    m.insert((2024, 15), aoc::year_2024::day_15::main as SolveFn);
-    m.insert((2024, 16), aoc::year_2024::day_16::main as SolveFn);
-    m.insert((2024, 17), aoc::year_2024::day_17::main as SolveFn);
-    m.insert((2024, 18), aoc::year_2024::day_18::main as SolveFn);
-    m.insert((2024, 19), aoc::year_2024::day_19::main as SolveFn);
-    m.insert((2024, 20), aoc::year_2024::day_20::main as SolveFn);
-    m.insert((2024, 21), aoc::year_2024::day_21::main as SolveFn);
-    m.insert((2024, 22), aoc::year_2024::day_22::main as SolveFn);
-    m.insert((2024, 23), aoc::year_2024::day_23::main as SolveFn);
-    m.insert((2024, 24), aoc::year_2024::day_24::main as SolveFn);
-    m.insert((2024, 25), aoc::year_2024::day_25::main as SolveFn);
-    m.insert((2025, 1), aoc::year_2025::day_01::main as SolveFn);
-    m.insert((2025, 2), aoc::year_2025::day_02::main as SolveFn);
-    m.insert((2025, 3), aoc::year_2025::day_03::main as SolveFn);
-    m.insert((2025, 4), aoc::year_2025::day_04::main as SolveFn);
-    m.insert((2025, 5), aoc::year_2025::day_05::main as SolveFn);
-    m.insert((2025, 6), aoc::year_2025::day_06::main as SolveFn);
-    m.insert((2025, 7), aoc::year_2025::day_07::main as SolveFn);
-    m.insert((2025, 8), aoc::year_2025::day_08::main as SolveFn);
-    
-    return m;
-});
-// --- AUTO GENERATED MAP END ---
+/// Solves a part from the input, or from the output of the day's parse stage.
+type PartFn<T> = fn(Part, &T) -> Result<Answer, Box<dyn Error>>;
+type ParseFn<T> = fn(&PuzzleInput) -> Result<T, Box<dyn Error>>;
+type RunFn = fn(&[Part], &PuzzleInput) -> SolveRun;
+
+/// A day's entry point and the puzzle parameters it declares.
+pub struct Solver {
+    pub run: RunFn,
+    pub params: &'static [Param],
+}
+
+/// The result of running one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveOutcome {
+    Answer(Answer),
+    Error(String),
+    Panic(String),
+}
+
+/// The outcome of each requested part and how long it took, not counting
+/// reading the input. For days with a `parse` stage, its time is reported
+/// separately and not included in the parts' times.
+#[derive(Debug, Clone)]
+pub struct SolveRun {
+    pub parse: Option<Duration>,
+    pub parts: Vec<(Part, SolveOutcome, Duration)>,
+}
+
+/// Runs a stage, capturing errors and panics so that one broken day can't abort
+/// a run over many days.
+fn timed<T>(
+    stage: impl FnOnce() -> Result<T, Box<dyn Error>>,
+) -> (Result<T, SolveOutcome>, Duration) {
+    let start = Instant::now();
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(stage));
+    let elapsed = start.elapsed();
+
+    let result = match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err(SolveOutcome::Error(e.to_string())),
+        Err(payload) => Err(SolveOutcome::Panic(
+            payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "solver panicked".to_string()),
+        )),
+    };
+
+    (result, elapsed)
+}
+
+fn outcome(result: Result<Answer, SolveOutcome>) -> SolveOutcome {
+    result.map_or_else(|outcome| outcome, SolveOutcome::Answer)
+}
+
+/// Runs a day whose `main` solves each part straight from the input.
+pub fn run_parts(parts: &[Part], input: &PuzzleInput, main: PartFn<PuzzleInput>) -> SolveRun {
+    SolveRun {
+        parse: None,
+        parts: parts
+            .iter()
+            .map(|&part| {
+                let (result, elapsed) = timed(|| main(part, input));
+                (part, outcome(result), elapsed)
+            })
+            .collect(),
+    }
+}
+
+/// Runs a day with a `parse` stage: the input is parsed once and the result is
+/// shared by the parts. If parsing fails, every part fails with its error.
+pub fn run_staged<T>(
+    parts: &[Part],
+    input: &PuzzleInput,
+    parse: ParseFn<T>,
+    main: PartFn<T>,
+) -> SolveRun {
+    let (parsed, parse_time) = timed(|| parse(input));
+
+    let parts = parts
+        .iter()
+        .map(|&part| match &parsed {
+            Ok(parsed) => {
+                let (result, elapsed) = timed(|| main(part, parsed));
+                (part, outcome(result), elapsed)
+            }
+            Err(outcome) => (part, outcome.clone(), Duration::ZERO),
+        })
+        .collect();
+
+    SolveRun {
+        parse: Some(parse_time),
+        parts,
+    }
+}
+
+/// Runs the registered solver for a day on some of its parts.
+pub fn run(year: i32, day: i32, parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    match SOLVERS.get(&(year, day)) {
+        Some(solver) => (solver.run)(parts, input),
+        None => SolveRun {
+            parse: None,
+            parts: parts
+                .iter()
+                .map(|&part| {
+                    let message = format!("Day {} not implemented for year {}.", day, year);
+                    (part, SolveOutcome::Error(message), Duration::ZERO)
+                })
+                .collect(),
+        },
+    }
+}
+
+/// Runs the registered solver for one part of a day and returns its answer.
+pub fn solve(
+    year: i32,
+    day: i32,
+    part: Part,
+    input: &PuzzleInput,
+) -> Result<Answer, Box<dyn Error>> {
+    match solve_timed(year, day, part, input).0 {
+        SolveOutcome::Answer(answer) => Ok(answer),
+        SolveOutcome::Error(e) => Err(e.into()),
+        SolveOutcome::Panic(message) => Err(format!("Solver panicked: {}", message).into()),
+    }
+}
+
+/// Runs one part and measures how long it takes, including the day's parse
+/// stage if it has one.
+pub fn solve_timed(
+    year: i32,
+    day: i32,
+    part: Part,
+    input: &PuzzleInput,
+) -> (SolveOutcome, Duration) {
+    let run = run(year, day, &[part], input);
+    let (_, outcome, elapsed) = run.parts.into_iter().next().unwrap();
+    (outcome, run.parse.unwrap_or_default() + elapsed)
+}
+
+/// Returns the parameters declared by a day (none if it isn't registered).
+pub fn params(year: i32, day: i32) -> &'static [Param] {
+    SOLVERS
+        .get(&(year, day))
+        .map_or(&[], |solver| solver.params)
+}
+
+/// Returns the registered (year, day) pairs in order, optionally for a single year.
+pub fn days(year: Option<i32>) -> Vec<(i32, i32)> {
+    let mut days: Vec<(i32, i32)> = SOLVERS
+        .keys()
+        .filter(|(y, _)| year.is_none_or(|year| *y == year))
+        .copied()
+        .collect();
+    days.sort();
+    days
+}
+
+// Generated by build.rs from the day folders under src/aoc
+include!(concat!(env!("OUT_DIR"), "/solvers_map.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params::Params;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    struct Numbers(Vec<i64>);
+
+    fn parse(input: &PuzzleInput) -> Result<Numbers, Box<dyn Error>> {
+        PARSES.fetch_add(1, Ordering::SeqCst);
+        Ok(Numbers(
+            input
+                .text
+                .lines()
+                .map(|line| line.parse())
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+
+    fn main(part: Part, Numbers(numbers): &Numbers) -> Result<Answer, Box<dyn Error>> {
+        Ok(match part {
+            Part::One => numbers.iter().sum::<i64>().into(),
+            Part::Two => numbers.iter().product::<i64>().into(),
+        })
+    }
+
+    fn input(text: &str) -> PuzzleInput {
+        PuzzleInput {
+            text: text.to_string(),
+            params: Params::default(),
+        }
+    }
+
+    #[test]
+    fn test_run_staged() {
+        let run = run_staged(&[Part::One, Part::Two], &input("2\n3\n4"), parse, main);
+
+        assert_eq!(PARSES.load(Ordering::SeqCst), 1);
+        assert!(run.parse.is_some());
+        assert_eq!(run.parts[0].1, SolveOutcome::Answer(Answer::Int(9)));
+        assert_eq!(run.parts[1].1, SolveOutcome::Answer(Answer::Int(24)));
+
+        let run = run_staged(&[Part::One, Part::Two], &input("2\nx"), parse, main);
+        assert!(matches!(run.parts[0].1, SolveOutcome::Error(_)));
+        assert!(matches!(run.parts[1].1, SolveOutcome::Error(_)));
+    }
+
+    #[test]
+    fn test_run_parts_catches_panics() {
+        fn main(part: Part, _: &PuzzleInput) -> Result<Answer, Box<dyn Error>> {
+            match part {
+                Part::One => Ok(1.into()),
+                Part::Two => panic!("not yet"),
+            }
+        }
+
+        let run = run_parts(&[Part::One, Part::Two], &input(""), main);
+        assert!(run.parse.is_none());
+        assert_eq!(run.parts[0].1, SolveOutcome::Answer(Answer::Int(1)));
+        assert_eq!(run.parts[1].1, SolveOutcome::Panic("not yet".to_string()));
+    }
+}
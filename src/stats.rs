@@ -0,0 +1,287 @@
+//! Personal stats: the times, ranks and scores from the site's per-year self
+//! leaderboard, joined with how long the local solvers take.
+
+use std::error::Error;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::client::Client;
+use crate::input::InputSource;
+use crate::puzzle;
+use crate::run::format_duration;
+use crate::solvers::{self, SolveOutcome};
+use crate::{OutputFormat, Part};
+
+/// One part of a day as reported by the site, plus the local solver runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartStats {
+    /// Time from the puzzle unlocking to the star, e.g. `00:01:23` or `>24h`.
+    pub time: String,
+    pub rank: Option<u32>,
+    pub score: Option<u32>,
+    pub runtime_ns: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayStats {
+    pub day: u32,
+    pub part_1: Option<PartStats>,
+    pub part_2: Option<PartStats>,
+}
+
+impl DayStats {
+    fn part_mut(&mut self, part: Part) -> Option<&mut PartStats> {
+        match part {
+            Part::One => self.part_1.as_mut(),
+            Part::Two => self.part_2.as_mut(),
+        }
+    }
+}
+
+/// Parses the fixed-width table on the self leaderboard page, returning the
+/// rows in order of day. Years without a global leaderboard only have the time
+/// columns, in which case the rank and score are left empty.
+pub fn parse(html: &str) -> Result<Vec<DayStats>, Box<dyn Error>> {
+    let start = html
+        .find("<pre>")
+        .ok_or("No stats table found on the page")?
+        + "<pre>".len();
+    let end = html[start..]
+        .find("</pre>")
+        .map_or(html.len(), |end| start + end);
+    let table = puzzle::decode_entities(&puzzle::strip_tags(&html[start..end]));
+
+    let mut rows = Vec::new();
+    for line in table.lines() {
+        let columns: Vec<&str> = line.split_whitespace().collect();
+        let day = match columns.first().and_then(|day| day.parse::<u32>().ok()) {
+            Some(day) => day,
+            None => continue, // Header lines
+        };
+
+        let per_part = match columns.len() - 1 {
+            6 => 3,
+            2 => 1,
+            _ => return Err(format!("Unexpected stats row: '{}'", line.trim()).into()),
+        };
+        let part = |columns: &[&str]| {
+            if columns[0] == "-" {
+                return None;
+            }
+            Some(PartStats {
+                time: columns[0].to_string(),
+                rank: columns.get(1).and_then(|rank| rank.parse().ok()),
+                score: columns.get(2).and_then(|score| score.parse().ok()),
+                runtime_ns: None,
+            })
+        };
+
+        rows.push(DayStats {
+            day,
+            part_1: part(&columns[1..1 + per_part]),
+            part_2: part(&columns[1 + per_part..]),
+        });
+    }
+
+    rows.sort_by_key(|row| row.day);
+    Ok(rows)
+}
+
+/// Runs the local solver for every solved part that has one and a downloaded
+/// input, recording how long it took. Parts whose solver fails are left
+/// without a runtime.
+fn add_runtimes(year: i32, rows: &mut [DayStats]) {
+    let local_days = solvers::days(Some(year));
+
+    for row in rows.iter_mut() {
+        let day = row.day as i32;
+        if !local_days.contains(&(year, day)) {
+            continue;
+        }
+        let input = match InputSource::Puzzle.read(year, day) {
+            Ok(input) => input,
+            Err(_) => continue,
+        };
+
+        for part in [Part::One, Part::Two] {
+            if let Some(stats) = row.part_mut(part) {
+                if let (SolveOutcome::Answer(_), elapsed) =
+                    solvers::solve_timed(year, day, part, &input)
+                {
+                    stats.runtime_ns = Some(elapsed.as_nanos() as u64);
+                }
+            }
+        }
+    }
+}
+
+/// Downloads the personal stats for a year, times the local solvers and prints
+/// the combined report.
+pub fn stats(year: i32, format: OutputFormat) -> Result<(), Box<dyn Error>> {
+    let html = Client::from_env()?.get_self_leaderboard(year)?;
+    let mut rows = parse(&html)?;
+    if rows.is_empty() {
+        return Err(format!("No stars for {} yet", year).into());
+    }
+
+    add_runtimes(year, &mut rows);
+
+    let output = match format {
+        OutputFormat::Table => render_table(&rows),
+        OutputFormat::Csv => render_csv(&rows),
+        OutputFormat::Json => serde_json::to_string_pretty(&rows)? + "\n",
+    };
+    print!("{}", output);
+
+    Ok(())
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or("-".to_string(), |v| v.to_string())
+}
+
+pub fn render_table(rows: &[DayStats]) -> String {
+    let mut out = format!(
+        "{:<5}{:>10}{:>8}{:>7}{:>11}  {:>10}{:>8}{:>7}{:>11}\n",
+        "Day", "P1 time", "Rank", "Score", "Runtime", "P2 time", "Rank", "Score", "Runtime"
+    );
+
+    for row in rows {
+        out.push_str(&format!("{:<5}", format!("{:02}", row.day)));
+        for (i, part) in [&row.part_1, &row.part_2].into_iter().enumerate() {
+            if i > 0 {
+                out.push_str("  ");
+            }
+            let (time, rank, score, runtime) = match part {
+                Some(stats) => (
+                    stats.time.clone(),
+                    optional(stats.rank),
+                    optional(stats.score),
+                    optional(
+                        stats
+                            .runtime_ns
+                            .map(|ns| format_duration(Duration::from_nanos(ns))),
+                    ),
+                ),
+                None => (
+                    "-".to_string(),
+                    "-".to_string(),
+                    "-".to_string(),
+                    "-".to_string(),
+                ),
+            };
+            out.push_str(&format!(
+                "{:>10}{:>8}{:>7}{:>11}",
+                time, rank, score, runtime
+            ));
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Renders one row per solved part.
+pub fn render_csv(rows: &[DayStats]) -> String {
+    fn field<T: ToString>(value: Option<T>) -> String {
+        value.map_or(String::new(), |v| v.to_string())
+    }
+
+    let mut out = "day,part,time,rank,score,runtime_ns\n".to_string();
+    for row in rows {
+        for (part, stats) in [(1, &row.part_1), (2, &row.part_2)] {
+            if let Some(stats) = stats {
+                out.push_str(&format!(
+                    "{},{},{},{},{},{}\n",
+                    row.day,
+                    part,
+                    stats.time,
+                    field(stats.rank),
+                    field(stats.score),
+                    field(stats.runtime_ns)
+                ));
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("fixtures/leaderboard_self.html");
+
+    fn part(time: &str, rank: u32, score: u32) -> Option<PartStats> {
+        Some(PartStats {
+            time: time.to_string(),
+            rank: Some(rank),
+            score: Some(score),
+            runtime_ns: None,
+        })
+    }
+
+    #[test]
+    fn test_parse() {
+        let rows = parse(FIXTURE).unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                DayStats {
+                    day: 1,
+                    part_1: part("00:01:23", 57, 44),
+                    part_2: part("00:02:05", 48, 53),
+                },
+                DayStats {
+                    day: 2,
+                    part_1: part("06:10:00", 31337, 0),
+                    part_2: part("08:16:45", 29876, 0),
+                },
+                DayStats {
+                    day: 3,
+                    part_1: part("00:09:12", 2042, 0),
+                    part_2: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_time_only() {
+        let html = "<pre>Day   Part 1   Part 2\n  5   &gt;24h          -\n  4   00:30:00   00:45:10\n</pre>";
+        let rows = parse(html).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].part_2.as_ref().unwrap().time, "00:45:10");
+        assert_eq!(rows[0].part_2.as_ref().unwrap().rank, None);
+        assert_eq!(rows[1].part_1.as_ref().unwrap().time, ">24h");
+        assert_eq!(rows[1].part_2, None);
+
+        assert!(parse("<html>Log In</html>").is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let mut rows = parse(FIXTURE).unwrap();
+        rows[0].part_1.as_mut().unwrap().runtime_ns = Some(1_500_000);
+
+        let table = render_table(&rows);
+        assert_eq!(
+            table.lines().nth(1).unwrap(),
+            "01     00:01:23      57     44     1.50ms    00:02:05      48     53          -"
+        );
+        assert!(table
+            .lines()
+            .nth(3)
+            .unwrap()
+            .ends_with("         -       -      -          -"));
+
+        let csv = render_csv(&rows);
+        assert_eq!(csv.lines().count(), 6);
+        assert_eq!(csv.lines().nth(1).unwrap(), "1,1,00:01:23,57,44,1500000");
+        assert_eq!(csv.lines().nth(5).unwrap(), "3,1,00:09:12,2042,0,");
+    }
+}
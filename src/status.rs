@@ -0,0 +1,266 @@
+//! A calendar of progress for each year: which days are scaffolded, have an
+//! input and recorded answers, and whether their example tests pass.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use chrono::{Datelike, NaiveDate, Utc};
+
+use crate::client;
+use crate::config;
+use crate::input;
+use crate::ledger::Ledger;
+use crate::solvers;
+use crate::Part;
+
+/// How far along a day is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: i32,
+    pub unlocked: bool,
+    /// The day has a folder with a `mod.rs`.
+    pub scaffolded: bool,
+    /// The day's solver is compiled in (it isn't until the next build after
+    /// `init`).
+    pub registered: bool,
+    pub input: bool,
+    pub part_1: bool,
+    pub part_2: bool,
+    /// Whether all the day's example tests passed, if they were run.
+    pub tests: Option<bool>,
+}
+
+/// Prints the calendar for one year, or for every year with a folder. With
+/// `tests`, the example tests are run first (with `cargo test`).
+pub fn status(year: Option<i32>, tests: bool) -> Result<(), Box<dyn Error>> {
+    let years = match year {
+        Some(year) => vec![year],
+        None => years(&input::aoc_dir())?,
+    };
+    if years.is_empty() {
+        return Err("No years found in src/aoc, run init first".into());
+    }
+
+    let results = if tests {
+        run_tests(year)?
+    } else {
+        BTreeMap::new()
+    };
+
+    for year in years {
+        let ledger = Ledger::load(year)?;
+        let registered = solvers::days(Some(year));
+
+        let days: Vec<DayStatus> = (1..=client::days_in(year))
+            .map(|day| DayStatus {
+                day,
+                unlocked: client::unlock_time(year, day).is_ok_and(|unlock| unlock <= Utc::now()),
+                scaffolded: Path::new(&input::day_folder(year, day))
+                    .join("mod.rs")
+                    .exists(),
+                registered: registered.contains(&(year, day)),
+                input: input::has_input(year, day),
+                part_1: ledger.get(day, Part::One).is_some(),
+                part_2: ledger.get(day, Part::Two).is_some(),
+                tests: results.get(&(year, day)).copied(),
+            })
+            .collect();
+
+        println!("{}", render(year, &days));
+    }
+
+    println!(
+        "S solver, s scaffolded but not built yet, I input, * answer recorded for part 1 and 2"
+    );
+    if tests {
+        println!("+ example tests pass, x example tests fail");
+    }
+
+    Ok(())
+}
+
+/// Returns the years that have a folder under `aoc_dir`, in order.
+fn years(aoc_dir: &Path) -> Result<Vec<i32>, Box<dyn Error>> {
+    let mut years = Vec::new();
+    for entry in fs::read_dir(aoc_dir)? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if let Some(year) = name.strip_prefix("year_").and_then(|y| y.parse().ok()) {
+            years.push(year);
+        }
+    }
+    years.sort();
+    Ok(years)
+}
+
+/// Runs the day tests (for a single year, or all of them) and returns whether
+/// each day's tests passed.
+fn run_tests(year: Option<i32>) -> Result<BTreeMap<(i32, i32), bool>, Box<dyn Error>> {
+    let filter = match year {
+        Some(year) => format!("aoc::year_{:04}::", year),
+        None => "aoc::".to_string(),
+    };
+
+    println!("Running cargo test {}...", filter);
+    // From the crate's folder, so that it works wherever status is run from
+    let output = Command::new(std::env::var("CARGO").unwrap_or("cargo".to_string()))
+        .args(["test", "--bin", env!("CARGO_PKG_NAME"), &filter])
+        .current_dir(config::crate_dir())
+        .output()?;
+
+    let results = parse_test_output(&String::from_utf8_lossy(&output.stdout));
+    if results.is_empty() && !output.status.success() {
+        return Err(format!(
+            "cargo test failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+    Ok(results)
+}
+
+/// Reads the `test aoc::year_2024::day_01::tests::test_part_1 ... ok` lines of
+/// `cargo test` output. A day passes if all of its tests do.
+pub fn parse_test_output(output: &str) -> BTreeMap<(i32, i32), bool> {
+    let mut results = BTreeMap::new();
+
+    for line in output.lines() {
+        let Some((name, result)) = line
+            .strip_prefix("test aoc::year_")
+            .and_then(|rest| rest.split_once(" ... "))
+        else {
+            continue;
+        };
+
+        let mut parts = name.split("::");
+        let year = parts.next().and_then(|y| y.parse::<i32>().ok());
+        let day = parts
+            .next()
+            .and_then(|d| d.strip_prefix("day_"))
+            .and_then(|d| d.parse::<i32>().ok());
+
+        if let (Some(year), Some(day)) = (year, day) {
+            let passed = result.trim() == "ok";
+            let entry = results.entry((year, day)).or_insert(true);
+            *entry = *entry && passed;
+        }
+    }
+
+    results
+}
+
+/// Lays a year's days out as a calendar, a week per row starting on Monday.
+/// Each day shows `S` (or `s`) and `I` when it has a solver and an input, a
+/// star per recorded answer, and `+` or `x` for its example tests.
+pub fn render(year: i32, days: &[DayStatus]) -> String {
+    let first = NaiveDate::from_ymd_opt(year, 12, 1).unwrap();
+    let offset = first.weekday().num_days_from_monday() as usize;
+
+    let mut out = format!("{}\n", year);
+    for name in ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"] {
+        out.push_str(&format!("{:<10}", name));
+    }
+    out = out.trim_end().to_string() + "\n";
+
+    let mut row = " ".repeat(10 * offset);
+    for (i, status) in days.iter().enumerate() {
+        row.push_str(&format!("{:<10}", cell(status)));
+        if (offset + i + 1).is_multiple_of(7) || i + 1 == days.len() {
+            out.push_str(row.trim_end());
+            out.push('\n');
+            row.clear();
+        }
+    }
+
+    let count = |f: fn(&DayStatus) -> bool| days.iter().filter(|d| f(d)).count();
+    out.push_str(&format!(
+        "Scaffolded {}/{}, inputs {}/{}, stars {}/{}\n",
+        count(|d| d.scaffolded),
+        days.len(),
+        count(|d| d.input),
+        days.len(),
+        count(|d| d.part_1) + count(|d| d.part_2),
+        2 * days.len()
+    ));
+
+    out
+}
+
+fn cell(status: &DayStatus) -> String {
+    if !status.unlocked {
+        return format!("{:>2}", status.day);
+    }
+
+    let flag = |set: bool, c: char| if set { c } else { '.' };
+    let solver = match (status.registered, status.scaffolded) {
+        (true, _) => 'S',
+        (false, true) => 's',
+        (false, false) => '.',
+    };
+    let tests = match status.tests {
+        Some(true) => '+',
+        Some(false) => 'x',
+        None => ' ',
+    };
+
+    format!(
+        "{:>2} {}{}{}{}{}",
+        status.day,
+        solver,
+        flag(status.input, 'I'),
+        flag(status.part_1, '*'),
+        flag(status.part_2, '*'),
+        tests
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_test_output() {
+        let output = "\
+running 5 tests
+test aoc::year_2024::day_01::tests::test_part_1 ... ok
+test aoc::year_2024::day_01::tests::test_part_2 ... ok
+test aoc::year_2024::day_02::tests::test_part_1 ... ok
+test aoc::year_2024::day_02::tests::test_part_2 ... FAILED
+test params::tests::test_set ... ok
+
+test result: FAILED. 4 passed; 1 failed";
+
+        let results = parse_test_output(output);
+        assert_eq!(results.len(), 2);
+        assert!(results[&(2024, 1)]);
+        assert!(!results[&(2024, 2)]);
+    }
+
+    #[test]
+    fn test_render() {
+        let days: Vec<DayStatus> = (1..=12)
+            .map(|day| DayStatus {
+                day,
+                unlocked: day <= 10,
+                scaffolded: day <= 3,
+                registered: day <= 2,
+                input: day <= 2,
+                part_1: day <= 2,
+                part_2: day == 1,
+                tests: (day <= 2).then_some(day == 1),
+            })
+            .collect();
+
+        // December 2025 starts on a Monday
+        let lines: Vec<String> = render(2025, &days).lines().map(String::from).collect();
+        assert_eq!(lines[0], "2025");
+        assert_eq!(
+            lines[2],
+            " 1 SI**+   2 SI*.x   3 s...    4 ....    5 ....    6 ....    7 ...."
+        );
+        assert_eq!(lines[3], " 8 ....    9 ....   10 ....   11        12");
+        assert_eq!(lines[4], "Scaffolded 3/12, inputs 2/12, stars 3/24");
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+use std::time::Duration;
+
+use regex::Regex;
+
+/// The result of posting an answer, as reported by the puzzle site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    AlreadySolved,
+    RateLimited(Duration),
+    Unknown(String),
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "Correct!"),
+            SubmitOutcome::Wrong => write!(f, "Wrong answer"),
+            SubmitOutcome::TooHigh => write!(f, "Wrong answer (too high)"),
+            SubmitOutcome::TooLow => write!(f, "Wrong answer (too low)"),
+            SubmitOutcome::AlreadySolved => write!(f, "Already solved"),
+            SubmitOutcome::RateLimited(wait) => {
+                write!(f, "Rate limited, wait {}s before retrying", wait.as_secs())
+            }
+            SubmitOutcome::Unknown(message) => write!(f, "Unrecognised response: {}", message),
+        }
+    }
+}
+
+impl SubmitOutcome {
+    pub fn is_correct(&self) -> bool {
+        matches!(self, SubmitOutcome::Correct)
+    }
+}
+
+/// Parses the HTML page returned after posting an answer.
+pub fn parse_response(html: &str) -> SubmitOutcome {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        return SubmitOutcome::Correct;
+    }
+
+    if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            return SubmitOutcome::TooHigh;
+        }
+        if text.contains("your answer is too low") {
+            return SubmitOutcome::TooLow;
+        }
+        return SubmitOutcome::Wrong;
+    }
+
+    if text.contains("You don't seem to be solving the right level") {
+        return SubmitOutcome::AlreadySolved;
+    }
+
+    if text.contains("You gave an answer too recently") {
+        let re = Regex::new(r"You have (?:(\d+)m\s*)?(\d+)s left to wait").unwrap();
+        let wait = re
+            .captures(&text)
+            .map(|caps| {
+                let minutes = caps
+                    .get(1)
+                    .map_or(0, |m| m.as_str().parse::<u64>().unwrap());
+                let seconds = caps[2].parse::<u64>().unwrap();
+                minutes * 60 + seconds
+            })
+            .unwrap_or(0);
+        return SubmitOutcome::RateLimited(Duration::from_secs(wait));
+    }
+
+    SubmitOutcome::Unknown(text)
+}
+
+/// Extracts the text of the `<article>` element (or the whole page if there is
+/// none), with tags stripped and whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let body = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    let text = tags.replace_all(body, "");
+
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{stub_server, Client};
+    use crate::Part;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let cases = vec![
+            (
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+                SubmitOutcome::Correct,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+                SubmitOutcome::Wrong,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+                SubmitOutcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                SubmitOutcome::TooLow,
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it? [<a href=\"/2024/day/1\">Return to Day 1</a>]",
+                SubmitOutcome::AlreadySolved,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 41s left to wait.",
+                SubmitOutcome::RateLimited(Duration::from_secs(41)),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 2m 5s left to wait.",
+                SubmitOutcome::RateLimited(Duration::from_secs(125)),
+            ),
+            (
+                "Something <em>else</em> happened.",
+                SubmitOutcome::Unknown("Something else happened.".to_string()),
+            ),
+        ];
+
+        for (message, expected) in cases {
+            assert_eq!(parse_response(&page(message)), expected);
+        }
+    }
+
+    #[test]
+    fn test_submit_to_stub_server() {
+        let (base_url, server) = stub_server("200 OK", &page("That's the right answer!"));
+
+        let client = Client::new(&base_url, "test-token");
+        let outcome = client.submit_answer(2024, 3, Part::Two, "48").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(outcome, SubmitOutcome::Correct);
+        assert!(request.starts_with("POST /2024/day/3/answer HTTP/1.1"));
+        assert!(request.contains("cookie: session=test-token"));
+        assert!(request.ends_with("level=2&answer=48"));
+    }
+}
@@ -0,0 +1,23 @@
+//! Helpers for writing CSV output.
+
+/// Quotes a field if it contains a comma, a quote or a newline, doubling any
+/// quotes inside it.
+pub fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("Alice"), "Alice");
+        assert_eq!(escape("Smith, \"J\""), "\"Smith, \"\"J\"\"\"");
+        assert_eq!(escape("two\nlines"), "\"two\nlines\"");
+    }
+}
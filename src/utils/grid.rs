@@ -1,12 +1,45 @@
 use std::{
-    collections::{HashMap, VecDeque},
     fmt,
+    hash::Hash,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
 };
 
+use crate::utils::search;
+
+/// A point on a grid, with `x` going right and `y` going down. Coordinates are
+/// `i32` unless another `Coordinate` type is given, e.g. `Point<i64>` for
+/// values too large for an `i32`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A signed integer type that can be used for the coordinates of a `Point`.
+pub trait Coordinate:
+    Copy
+    + Eq
+    + Ord
+    + Hash
+    + fmt::Debug
+    + From<i32>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+}
+
+impl Coordinate for i32 {}
+impl Coordinate for i64 {}
+impl Coordinate for i128 {}
+
+fn abs<T: Coordinate>(value: T) -> T {
+    if value < T::from(0) {
+        -value
+    } else {
+        value
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -28,56 +61,45 @@ pub struct PointWithDirection {
     pub direction: Direction,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Grid<T> {
-    pub data: Vec<Vec<T>>,
-}
-
-impl Point {
-    pub fn new(x: i32, y: i32) -> Self {
-        Point { x, y }
+/// Offsets of the neighbours of a point: the four orthogonal ones (N, E, S, W)
+/// first, then the diagonals (NE, SE, SW, NW).
+const NEIGHBOUR_DELTAS: [(i32, i32); 8] = [
+    (0, -1),
+    (1, 0),
+    (0, 1),
+    (-1, 0),
+    (1, -1),
+    (1, 1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// Offsets of the neighbours to look at, without allocating.
+fn neighbour_deltas(include_diagonal: bool) -> &'static [(i32, i32)] {
+    if include_diagonal {
+        &NEIGHBOUR_DELTAS
+    } else {
+        &NEIGHBOUR_DELTAS[..4]
     }
+}
 
-    pub fn neighbours(&self, include_diagonal: bool) -> Vec<Point> {
-        let directions: Vec<(i32, i32)> = if include_diagonal {
-            vec![
-                (0, -1),  // N
-                (1, 0),   // E
-                (0, 1),   // S
-                (-1, 0),  // W
-                (1, -1),  // NE
-                (1, 1),   // SE
-                (-1, 1),  // SW
-                (-1, -1), // NW
-            ]
-        } else {
-            vec![
-                (0, -1), // N
-                (1, 0),  // E
-                (0, 1),  // S
-                (-1, 0), // W
-            ]
-        };
-        return directions
-            .iter()
-            .map(|(dx, dy)| Point {
-                x: self.x + dx,
-                y: self.y + dy,
-            })
-            .collect();
+impl Direction {
+    /// The four cardinal directions, clockwise from north.
+    pub fn cardinals() -> impl Iterator<Item = Direction> {
+        use Direction::*;
+        [N, E, S, W].into_iter()
     }
 
-    pub fn with_direction(&self, direction: Direction) -> PointWithDirection {
-        PointWithDirection {
-            x: self.x,
-            y: self.y,
-            direction,
-        }
+    /// All eight directions, clockwise from north.
+    pub fn all() -> impl Iterator<Item = Direction> {
+        use Direction::*;
+        [N, NE, E, SE, S, SW, W, NW].into_iter()
     }
 
-    pub fn next_points_in_direction(&self, direction: Direction, num: i32) -> Vec<Point> {
+    /// The step `(dx, dy)` one point in this direction, with `y` going down.
+    pub fn delta(&self) -> (i32, i32) {
         use Direction::*;
-        let (dx, dy) = match direction {
+        match self {
             N => (0, -1),
             NE => (1, -1),
             E => (1, 0),
@@ -86,39 +108,189 @@ impl Point {
             SW => (-1, 1),
             W => (-1, 0),
             NW => (-1, -1),
-        };
-        (1..=num)
-            .map(|i| Point {
-                x: self.x + dx * i,
-                y: self.y + dy * i,
-            })
-            .collect()
+        }
     }
 
-    /// Returns all points within the given Manhattan distance from this point.
-    pub fn in_range(&self, distance: i32) -> Vec<Point> {
-        if distance <= 0 {
-            return Vec::new();
+    /// The direction after turning clockwise by `eighths` 45 degree steps
+    /// (counterclockwise if negative).
+    fn turned(&self, eighths: i32) -> Direction {
+        let index = (*self as i32 + eighths).rem_euclid(8);
+        Direction::all()
+            .nth(index as usize)
+            .expect("There are 8 directions")
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        self.turned(2)
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        self.turned(-2)
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.turned(4)
+    }
+
+    /// The fewest 90 degree turns to face `other`. A 45 degree difference counts
+    /// as a whole turn.
+    pub fn turns_between(&self, other: Direction) -> usize {
+        let eighths = (other as i32 - *self as i32).rem_euclid(8);
+        (eighths.min(8 - eighths) as usize).div_ceil(2)
+    }
+
+    /// Parses an arrow (`^`, `>`, `v`, `<`) or a compass letter (`N`, `E`, `S`,
+    /// `W`).
+    pub fn from_char(c: char) -> Option<Direction> {
+        use Direction::*;
+        match c {
+            '^' | 'N' => Some(N),
+            '>' | 'E' => Some(E),
+            'v' | 'S' => Some(S),
+            '<' | 'W' => Some(W),
+            _ => None,
+        }
+    }
+
+    /// The arrow for a cardinal direction, or the slash along a diagonal one.
+    pub fn to_char(&self) -> char {
+        use Direction::*;
+        match self {
+            N => '^',
+            E => '>',
+            S => 'v',
+            W => '<',
+            NE | SW => '/',
+            NW | SE => '\\',
         }
+    }
+}
+
+/// A rectangular grid, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Coordinate> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    pub fn neighbours(&self, include_diagonal: bool) -> Vec<Point<T>> {
+        self.neighbours_iter(include_diagonal).collect()
+    }
+
+    /// Same as `neighbours`, without building a `Vec`.
+    pub fn neighbours_iter(&self, include_diagonal: bool) -> impl Iterator<Item = Point<T>> {
+        let p = *self;
+        neighbour_deltas(include_diagonal)
+            .iter()
+            .map(move |&(dx, dy)| p + Point::new(T::from(dx), T::from(dy)))
+    }
+
+    /// The next point in a direction.
+    pub fn step(&self, direction: Direction) -> Point<T> {
+        let (dx, dy) = direction.delta();
+        *self + Point::new(T::from(dx), T::from(dy))
+    }
+
+    pub fn next_points_in_direction(&self, direction: Direction, num: i32) -> Vec<Point<T>> {
+        let (dx, dy) = direction.delta();
+        let delta = Point::new(T::from(dx), T::from(dy));
+        (1..=num).map(|i| *self + delta * T::from(i)).collect()
+    }
+
+    /// Returns all points within the given Manhattan distance from this point.
+    pub fn in_range(&self, distance: T) -> Vec<Point<T>> {
+        let (zero, one) = (T::from(0), T::from(1));
         let mut points = Vec::new();
-        for dx in -distance..=distance {
-            let max_dy = distance - dx.abs();
-            for dy in -max_dy..=max_dy {
-                if dx == 0 && dy == 0 {
-                    continue;
+        // Ranges of a generic T can't be iterated, so step through them by hand
+        let mut dx = -distance;
+        while dx <= distance {
+            let max_dy = distance - abs(dx);
+            let mut dy = -max_dy;
+            while dy <= max_dy {
+                if dx != zero || dy != zero {
+                    points.push(*self + Point::new(dx, dy));
                 }
-                points.push(Point {
-                    x: self.x + dx,
-                    y: self.y + dy,
-                });
+                dy = dy + one;
             }
+            dx = dx + one;
         }
         points
     }
 
     /// Returns the Manhattan distance between two points.
-    pub fn manhattan_distance(&self, other: &Point) -> i32 {
-        (self.x - other.x).abs() + (self.y - other.y).abs()
+    pub fn manhattan_distance(&self, other: &Point<T>) -> T {
+        abs(self.x - other.x) + abs(self.y - other.y)
+    }
+
+    /// Rotates the point 90 degrees clockwise (as drawn, with `y` going down)
+    /// around the origin, e.g. turns a north step into an east one.
+    pub fn rotate_clockwise(&self) -> Point<T> {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Rotates the point 90 degrees counterclockwise around the origin.
+    pub fn rotate_counterclockwise(&self) -> Point<T> {
+        Point::new(self.y, -self.x)
+    }
+}
+
+impl Point {
+    pub fn with_direction(&self, direction: Direction) -> PointWithDirection {
+        PointWithDirection {
+            x: self.x,
+            y: self.y,
+            direction,
+        }
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, factor: T) -> Point<T> {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Coordinate> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        *self = *self - other;
     }
 }
 
@@ -135,74 +307,23 @@ impl PointWithDirection {
     }
 
     pub fn neighbours(&self, include_diagonal: bool) -> Vec<Point> {
-        let directions: Vec<(i32, i32)> = if include_diagonal {
-            vec![
-                (0, -1),  // N
-                (1, 0),   // E
-                (0, 1),   // S
-                (-1, 0),  // W
-                (1, -1),  // NE
-                (1, 1),   // SE
-                (-1, 1),  // SW
-                (-1, -1), // NW
-            ]
-        } else {
-            vec![
-                (0, -1), // N
-                (1, 0),  // E
-                (0, 1),  // S
-                (-1, 0), // W
-            ]
-        };
+        self.as_point().neighbours(include_diagonal)
+    }
 
-        return directions
-            .iter()
-            .map(|(dx, dy)| Point {
-                x: self.x + dx,
-                y: self.y + dy,
-            })
-            .collect();
+    /// Same as `neighbours`, without building a `Vec`.
+    pub fn neighbours_iter(&self, include_diagonal: bool) -> impl Iterator<Item = Point> {
+        self.as_point().neighbours_iter(include_diagonal)
     }
 
     pub fn turn_clockwise(&self, degrees: i32) -> Self {
-        let num_steps = ((degrees / 45) % 8 + 8) % 8; // ensure positive modulo
-        let new_dir = ((self.direction as i32 + num_steps) % 8) as u8;
-        let new_direction = match new_dir {
-            0 => Direction::N,
-            1 => Direction::NE,
-            2 => Direction::E,
-            3 => Direction::SE,
-            4 => Direction::S,
-            5 => Direction::SW,
-            6 => Direction::W,
-            7 => Direction::NW,
-            _ => panic!("Invalid direction value: {}", new_dir),
-        };
-
-        return PointWithDirection {
-            x: self.x,
-            y: self.y,
-            direction: new_direction,
-        };
+        self.as_point()
+            .with_direction(self.direction.turned(degrees / 45))
     }
 
     pub fn next_step(&self) -> PointWithDirection {
-        use Direction::*;
-        let (dx, dy) = match self.direction {
-            N => (0, -1),
-            NE => (1, -1),
-            E => (1, 0),
-            SE => (1, 1),
-            S => (0, 1),
-            SW => (-1, 1),
-            W => (-1, 0),
-            NW => (-1, -1),
-        };
-        return PointWithDirection {
-            x: self.x + dx,
-            y: self.y + dy,
-            direction: self.direction,
-        };
+        self.as_point()
+            .step(self.direction)
+            .with_direction(self.direction)
     }
 }
 
@@ -211,31 +332,152 @@ impl<T> IntoIterator for Grid<T> {
     type IntoIter = std::vec::IntoIter<Vec<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.data.into_iter()
+        let mut cells = self.data.into_iter();
+        (0..self.height)
+            .map(|_| cells.by_ref().take(self.width).collect())
+            .collect::<Vec<_>>()
+            .into_iter()
     }
 }
 
 impl<'a, T> IntoIterator for &'a Grid<T> {
     type Item = &'a [T];
-    type IntoIter = std::iter::Map<std::slice::Iter<'a, Vec<T>>, fn(&'a Vec<T>) -> &'a [T]>;
+    type IntoIter = std::slice::Chunks<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.data.iter().map(|row| row.as_slice())
+        self.rows()
     }
 }
 
 impl<'a, T> IntoIterator for &'a mut Grid<T> {
     type Item = &'a mut T;
-    type IntoIter = std::iter::Flatten<std::slice::IterMut<'a, Vec<T>>>;
+    type IntoIter = std::slice::IterMut<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.data.iter_mut().flatten()
+        self.data.iter_mut()
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(&p)
+            .unwrap_or_else(|| panic!("Point {} is outside the grid", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(&p)
+            .unwrap_or_else(|| panic!("Point {} is outside the grid", p))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Position of a point in the row by row storage, if it's in the grid.
+    pub fn index_of(&self, p: &Point) -> Option<usize> {
+        if p.x < 0 || p.y < 0 || p.x as usize >= self.width || p.y as usize >= self.height {
+            return None;
+        }
+        Some(p.y as usize * self.width + p.x as usize)
+    }
+
+    /// The point at a position in the row by row storage.
+    pub fn point_at(&self, index: usize) -> Point {
+        Point {
+            x: (index % self.width) as i32,
+            y: (index / self.width) as i32,
+        }
+    }
+
+    /// All the cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        // chunks() needs a non-zero size, and an empty grid has no rows anyway
+        self.data.chunks(self.width.max(1))
+    }
+
+    /// The cells of a column, top to bottom. Columns aren't contiguous, so this
+    /// is an iterator rather than a slice. Panics if the column is outside the
+    /// grid, like `row`.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside the grid", x);
+        self.data.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn get(&self, p: &Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.data[i])
+    }
+
+    pub fn get_mut(&mut self, p: &Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.data[i])
+    }
+
+    pub fn get_xy(&self, x: usize, y: usize) -> Option<&T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.data.get(y * self.width + x)
+    }
+
+    pub fn set(&mut self, p: &Point, value: T) {
+        if let Some(cell) = self.get_mut(p) {
+            *cell = value;
+        }
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    pub fn in_bounds(&self, p: &Point) -> bool {
+        self.index_of(p).is_some()
     }
 }
 
 impl<T: PartialEq> Grid<T> {
-    pub fn new(data: Vec<Vec<T>>) -> Self {
-        Grid { data }
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn new(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            panic!(
+                "Grid rows must all have the same length, row {} has {} cells instead of {}",
+                y,
+                row.len(),
+                width
+            );
+        }
+
+        Grid {
+            data: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
     }
 
     pub fn init(height: usize, width: usize, value: T) -> Grid<T>
@@ -243,7 +485,9 @@ impl<T: PartialEq> Grid<T> {
         T: Clone,
     {
         Grid {
-            data: vec![vec![value.clone(); width]; height],
+            data: vec![value; width * height],
+            width,
+            height,
         }
     }
 
@@ -251,65 +495,37 @@ impl<T: PartialEq> Grid<T> {
     where
         T: Clone,
     {
-        Grid {
-            data: vec![vec![default; cols]; rows],
-        }
-    }
-
-    pub fn size(&self) -> (usize, usize) {
-        let rows = self.data.len();
-        let cols = if rows > 0 { self.data[0].len() } else { 0 };
-        (rows, cols)
+        Grid::init(rows, cols, default)
     }
 
     pub fn find(&self, value: &T) -> Option<Point> {
-        for (y, row) in self.data.iter().enumerate() {
-            for (x, item) in row.iter().enumerate() {
-                if item == value {
-                    return Some(Point {
-                        x: x as i32,
-                        y: y as i32,
-                    });
-                }
-            }
-        }
-        None
+        self.data
+            .iter()
+            .position(|item| item == value)
+            .map(|i| self.point_at(i))
     }
 
     pub fn find_all(&self, value: &T) -> Vec<Point> {
-        let mut points = Vec::new();
-        for (y, row) in self.data.iter().enumerate() {
-            for (x, item) in row.iter().enumerate() {
-                if item == value {
-                    points.push(Point {
-                        x: x as i32,
-                        y: y as i32,
-                    });
-                }
-            }
-        }
-        return points;
-    }
-
-    pub fn get(&self, p: &Point) -> Option<&T> {
         self.data
-            .get(p.y as usize)
-            .and_then(|row| row.get(p.x as usize))
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| *item == value)
+            .map(|(i, _)| self.point_at(i))
+            .collect()
     }
 
     pub fn neighbours(&self, p: &Point, include_diagonal: bool) -> Vec<Point> {
-        p.neighbours(include_diagonal)
-            .into_iter()
-            .filter(|n| self.in_bounds(n))
-            .collect()
+        self.neighbours_iter(p, include_diagonal).collect()
     }
 
-    pub fn set(&mut self, p: &Point, value: T) {
-        if let Some(row) = self.data.get_mut(p.y as usize) {
-            if let Some(cell) = row.get_mut(p.x as usize) {
-                *cell = value;
-            }
-        }
+    /// Same as `neighbours`, without building a `Vec`.
+    pub fn neighbours_iter<'a>(
+        &'a self,
+        p: &Point,
+        include_diagonal: bool,
+    ) -> impl Iterator<Item = Point> + 'a {
+        p.neighbours_iter(include_diagonal)
+            .filter(move |n| self.in_bounds(n))
     }
 
     /// Rotates the grid 90 degrees clockwise `times` times.
@@ -321,14 +537,17 @@ impl<T: PartialEq> Grid<T> {
         let mut result = self.clone();
         let mut t = ((times % 4) + 4) % 4; // normalize to [0,3]
         while t > 0 {
+            // Row y of the new grid is column y of the old one, bottom to top
             let (rows, cols) = result.size();
-            let mut new_data = vec![vec![result.data[0][0].clone(); rows]; cols];
-            for y in 0..rows {
-                for x in 0..cols {
-                    new_data[x][rows - 1 - y] = result.data[y][x].clone();
-                }
-            }
-            result = Grid { data: new_data };
+            let data = (0..cols)
+                .flat_map(|x| (0..rows).rev().map(move |y| (x, y)))
+                .map(|(x, y)| result.data[y * cols + x].clone())
+                .collect();
+            result = Grid {
+                data,
+                width: rows,
+                height: cols,
+            };
             t -= 1;
         }
         result
@@ -345,17 +564,8 @@ impl<T: PartialEq> Grid<T> {
         }
     }
 
-    pub fn get_xy(&self, x: usize, y: usize) -> Option<&T> {
-        self.data.get(y).and_then(|row| row.get(x))
-    }
-
     pub fn compare(&self, other: &Grid<T>) -> bool {
-        self.data == other.data
-    }
-
-    pub fn in_bounds(&self, p: &Point) -> bool {
-        let (rows, cols) = self.size();
-        p.x >= 0 && p.y >= 0 && (p.y as usize) < rows && (p.x as usize) < cols
+        self == other
     }
 
     /// Finds the shortest path from start to end, treating `walls` as impassable.
@@ -363,47 +573,16 @@ impl<T: PartialEq> Grid<T> {
     where
         T: std::cmp::PartialEq<char>,
     {
-        let mut queue = VecDeque::new();
-        let mut visited = HashMap::new();
-        let mut came_from = HashMap::new();
-
-        queue.push_back(start);
-        visited.insert(start, true);
-
-        while let Some(current) = queue.pop_front() {
-            if current == end {
-                // Reconstruct the path
-                let mut path = vec![end];
-                let mut curr = end;
-                while let Some(&prev) = came_from.get(&curr) {
-                    path.push(prev);
-                    curr = prev;
-                }
-                path.reverse();
-                return path;
-            }
-
-            for p in current.neighbours(false) {
-                if !self.in_bounds(&p) {
-                    continue;
-                }
-
-                if visited.contains_key(&p) {
-                    continue;
-                }
-
-                if let Some(cell) = self.get(&p) {
-                    if cell == walls {
-                        continue;
-                    }
-                }
-
-                queue.push_back(p);
-                visited.insert(p, true);
-                came_from.insert(p, current);
-            }
-        }
-        Vec::new() // No path found
+        search::bfs(
+            start,
+            |current| {
+                self.neighbours_iter(current, false)
+                    .filter(|p| self.get(p).is_some_and(|cell| cell != walls))
+            },
+            |current| *current == end,
+        )
+        .path()
+        .unwrap_or_default() // No path found
     }
 
     pub fn print_path(&self, path: &Vec<Point>)
@@ -423,17 +602,7 @@ impl<T: PartialEq> Grid<T> {
     {
         let mut grid_copy = self.clone();
         for point in path {
-            let dir_char = match point.direction {
-                Direction::E => '>',
-                Direction::W => '<',
-                Direction::S => 'v',
-                Direction::N => '^',
-                Direction::NE => '/',
-                Direction::NW => '\\',
-                Direction::SE => '\\',
-                Direction::SW => '/',
-            };
-            grid_copy.set(&point.as_point(), T::from(dir_char));
+            grid_copy.set(&point.as_point(), T::from(point.direction.to_char()));
         }
         println!("{}", grid_copy);
     }
@@ -452,14 +621,14 @@ impl<T: PartialEq> Grid<T> {
 
 impl<T: fmt::Display> fmt::Display for Grid<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (i, row) in self.data.iter().enumerate() {
+        for (i, row) in self.rows().enumerate() {
             for (j, item) in row.iter().enumerate() {
                 write!(f, "{}", item)?;
                 if j + 1 < row.len() {
                     write!(f, " ")?;
                 }
             }
-            if i + 1 < self.data.len() {
+            if i + 1 < self.height {
                 writeln!(f)?;
             }
         }
@@ -467,7 +636,7 @@ impl<T: fmt::Display> fmt::Display for Grid<T> {
     }
 }
 
-impl fmt::Display for Point {
+impl<T: fmt::Display> fmt::Display for Point<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "({}, {})", self.x, self.y)
     }
@@ -495,14 +664,23 @@ impl fmt::Display for PointWithDirection {
     }
 }
 
+/// The rows of a grid in the input, ignoring blank lines at the end so an
+/// extra newline doesn't make the grid ragged.
+fn grid_lines(input: &str) -> std::str::Lines<'_> {
+    input.trim_end_matches(['\n', '\r']).lines()
+}
+
 pub fn parse_string_grid(input: &str) -> Grid<char> {
-    Grid::new(input.lines().map(|line| line.chars().collect()).collect())
+    Grid::new(
+        grid_lines(input)
+            .map(|line| line.chars().collect())
+            .collect(),
+    )
 }
 
 pub fn parse_number_grid(input: &str) -> Grid<u32> {
     Grid::new(
-        input
-            .lines()
+        grid_lines(input)
             .map(|line| {
                 line.chars()
                     .map(|c| c.to_digit(10).expect("Failed to parse digit"))
@@ -511,3 +689,207 @@ pub fn parse_number_grid(input: &str) -> Grid<u32> {
             .collect(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_flat_storage() {
+        let mut grid = parse_string_grid("abc\ndef");
+        assert_eq!(grid.size(), (2, 3));
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert!(std::panic::catch_unwind(|| grid.column(3).count()).is_err());
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.index_of(&Point::new(2, 1)), Some(5));
+        assert_eq!(grid.index_of(&Point::new(3, 0)), None);
+        assert_eq!(grid.index_of(&Point::new(-1, 0)), None);
+        assert_eq!(grid.point_at(4), Point::new(1, 1));
+
+        assert_eq!(grid[Point::new(2, 0)], 'c');
+        grid[Point::new(2, 0)] = 'x';
+        grid.set(&Point::new(0, 1), 'y');
+        grid.set(&Point::new(5, 5), 'z');
+        assert_eq!(grid.row(0), &['a', 'b', 'x']);
+        assert_eq!(grid.get_xy(0, 1), Some(&'y'));
+        assert_eq!(grid.get_xy(3, 0), None);
+        assert_eq!(grid.find_all(&'e'), vec![Point::new(1, 1)]);
+        assert_eq!(grid.to_string(), "a b x\ny e f");
+    }
+
+    #[test]
+    fn test_trailing_blank_lines() {
+        assert_eq!(parse_string_grid("ab\ncd\n\n"), parse_string_grid("ab\ncd"));
+        assert_eq!(parse_number_grid("12\r\n34\r\n\r\n").size(), (2, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 has 1 cells instead of 2")]
+    fn test_ragged_rows() {
+        parse_string_grid("ab\nc\nde");
+    }
+
+    #[test]
+    fn test_rotate() {
+        let grid = parse_string_grid("abc\ndef");
+        let rotated = grid.rotate(1);
+        assert_eq!(rotated.size(), (3, 2));
+        assert_eq!(rotated.to_string(), "d a\ne b\nf c");
+        assert_eq!(grid.rotate(-1).to_string(), "c f\nb e\na d");
+        assert_eq!(grid.rotate(4), grid);
+        assert_eq!(
+            grid.into_iter().collect::<Vec<_>>(),
+            vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point::new(0, 0);
+        assert_eq!(
+            p.neighbours_iter(false).collect::<Vec<_>>(),
+            p.neighbours(false)
+        );
+        assert_eq!(p.neighbours_iter(true).count(), 8);
+        assert_eq!(p.step(Direction::NE), Point::new(1, -1));
+        assert_eq!(
+            p.step(Direction::W),
+            p.next_points_in_direction(Direction::W, 1)[0]
+        );
+
+        let grid = parse_string_grid("...\n...");
+        assert_eq!(
+            grid.neighbours_iter(&p, true).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_directions() {
+        use Direction::*;
+        assert_eq!(Direction::cardinals().collect::<Vec<_>>(), vec![N, E, S, W]);
+        assert_eq!(Direction::all().count(), 8);
+        assert_eq!(NE.delta(), (1, -1));
+        assert_eq!(W.opposite(), E);
+        assert_eq!(SE.opposite(), NW);
+        assert_eq!(N.turn_right(), E);
+        assert_eq!(N.turn_left(), W);
+        assert_eq!(SW.turn_right(), NW);
+
+        assert_eq!(N.turns_between(N), 0);
+        assert_eq!(N.turns_between(W), 1);
+        assert_eq!(E.turns_between(W), 2);
+        assert_eq!(N.turns_between(NE), 1);
+
+        for dir in Direction::cardinals() {
+            assert_eq!(Direction::from_char(dir.to_char()), Some(dir));
+            assert_eq!(
+                Direction::from_char(dir.to_string().chars().next().unwrap()),
+                Some(dir)
+            );
+        }
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(NW.to_char(), '\\');
+
+        let p = PointWithDirection::new(0, 0, N);
+        assert_eq!(p.turn_clockwise(90).direction, E);
+        assert_eq!(p.turn_clockwise(-45).direction, NW);
+    }
+
+    #[test]
+    fn test_point_arithmetic() {
+        let (a, b) = (Point::new(1, 2), Point::new(4, -1));
+        assert_eq!(a + b, Point::new(5, 1));
+        assert_eq!(b - a, Point::new(3, -3));
+        assert_eq!(a * 3, Point::new(3, 6));
+        assert_eq!(-a, Point::new(-1, -2));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+
+        let north = Point::new(0, -1);
+        assert_eq!(north.rotate_clockwise(), Point::new(1, 0));
+        assert_eq!(north.rotate_counterclockwise(), Point::new(-1, 0));
+        assert_eq!(north.rotate_clockwise().rotate_counterclockwise(), north);
+
+        let big: Point<i64> = Point::new(10_000_000_000_000, 1);
+        assert_eq!(big * 2 - big, big);
+        assert_eq!(big.step(Direction::S), Point::new(10_000_000_000_000, 2));
+        assert_eq!(
+            big.manhattan_distance(&Point::new(0, 0)),
+            10_000_000_000_001
+        );
+        assert_eq!(big.in_range(2).len(), 12);
+        assert_eq!(a.in_range(0), vec![]);
+    }
+
+    /// Length of the shortest path, with the neighbours of each point from `neighbours`.
+    fn bfs<I>(
+        grid: &Grid<char>,
+        start: Point,
+        end: Point,
+        neighbours: impl Fn(&Point) -> I,
+    ) -> usize
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let mut queue = VecDeque::from([(start, 0)]);
+        // Visited cells in a flat Vec, so the neighbours are most of the work
+        let mut visited = vec![false; grid.width() * grid.height()];
+        while let Some((current, steps)) = queue.pop_front() {
+            if current == end {
+                return steps + 1;
+            }
+            for p in neighbours(&current) {
+                let Some(i) = grid.index_of(&p) else {
+                    continue;
+                };
+                if grid.cells()[i] != '#' && !visited[i] {
+                    visited[i] = true;
+                    queue.push_back((p, steps + 1));
+                }
+            }
+        }
+        0
+    }
+
+    /// Micro-benchmark of the neighbour iteration on a BFS, run with
+    /// `cargo test --release bench_shortest_path -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_shortest_path() {
+        let size = 141;
+        let mut grid = Grid::init(size, size, '.');
+        for y in (2..size).step_by(4) {
+            let gap = if y % 8 == 2 { size - 1 } else { 0 };
+            for x in (0..size).filter(|&x| x != gap) {
+                grid[Point::new(x as i32, y as i32)] = '#';
+            }
+        }
+        let (start, end) = (Point::new(0, 0), Point::new(0, size as i32 - 1));
+        let iterations = 50;
+
+        let time = |f: &dyn Fn() -> usize| {
+            f(); // warm up
+            let started = std::time::Instant::now();
+            let lengths: Vec<usize> = (0..iterations).map(|_| f()).collect();
+            (started.elapsed() / iterations, lengths[0])
+        };
+        let (allocating, expected) = time(&|| bfs(&grid, start, end, |p| p.neighbours(false)));
+        let (iterating, length) = time(&|| bfs(&grid, start, end, |p| p.neighbours_iter(false)));
+
+        let (searching, path_length) = time(&|| grid.shortest_path(start, end, &'#').len());
+
+        println!("Vec neighbours:  {:?} per search", allocating);
+        println!("iter neighbours: {:?} per search", iterating);
+        println!("shortest_path:   {:?} per search", searching);
+        assert_eq!(length, expected);
+        assert_eq!(path_length, expected);
+    }
+}
@@ -1,2 +1,4 @@
+pub mod csv;
 pub mod grid;
 pub mod parsing;
+pub mod search;
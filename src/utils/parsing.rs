@@ -1,3 +1,8 @@
+use std::collections::HashMap;
+
+/// The neighbours of each node, by name.
+pub type Graph = HashMap<String, Vec<String>>;
+
 pub fn read_lines(input: &str) -> Vec<String> {
     input.lines().map(|line| line.to_string()).collect()
 }
@@ -47,3 +52,14 @@ pub fn read_groups_of_lines(input: &str) -> Vec<Vec<String>> {
         })
         .collect::<Vec<Vec<String>>>()
 }
+
+/// Reads an undirected graph from lines of `a-b` edges.
+pub fn read_graph(input: &str) -> Graph {
+    let mut graph = Graph::new();
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let (a, b) = line.trim().split_once('-').expect("Invalid edge");
+        graph.entry(a.to_string()).or_default().push(b.to_string());
+        graph.entry(b.to_string()).or_default().push(a.to_string());
+    }
+    graph
+}
@@ -0,0 +1,357 @@
+//! Shortest path searches over any kind of state: a point, a point with a
+//! direction, a whole puzzle position, ...
+//!
+//! The caller gives a start state, the neighbours of a state (with the cost of
+//! each move for Dijkstra and A*, which must be at least 1) and which states
+//! are goals. The search stops
+//! once every goal at the best distance has been found, so to get the distance
+//! to every reachable state pass a goal that never matches.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::hash_map::Entry as MapEntry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// What a search found: the distance to every state it reached and, for each
+/// of them, all the states that lead to it on a shortest path.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S> {
+    pub start: S,
+    /// Distance from the start to each reached state.
+    pub distances: HashMap<S, u64>,
+    /// The first state found before each state on a shortest path, and where
+    /// its other predecessors start in `other_predecessors`. Together they form
+    /// a DAG of all the shortest paths.
+    predecessors: HashMap<S, (S, Option<usize>)>,
+    /// Predecessors found after the first one, each pointing at the next one
+    /// for the same state. Keeping them all in one `Vec` means ties don't
+    /// allocate per state.
+    other_predecessors: Vec<(S, Option<usize>)>,
+    /// The goal states at the best distance, in the order they were found.
+    pub goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash> SearchResult<S> {
+    fn new(start: S) -> Self {
+        SearchResult {
+            distances: HashMap::from([(start.clone(), 0)]),
+            predecessors: HashMap::new(),
+            other_predecessors: Vec::new(),
+            goals: Vec::new(),
+            start,
+        }
+    }
+
+    /// Records a move to `next` at `distance`, returning whether it's the first
+    /// or a shorter way there, i.e. whether `next` needs (re)visiting.
+    fn reach(&mut self, from: &S, next: S, distance: u64) -> bool {
+        match self.distances.entry(next.clone()) {
+            MapEntry::Occupied(known) if *known.get() < distance => false,
+            MapEntry::Occupied(known) if *known.get() == distance => {
+                if let Some((_, others)) = self.predecessors.get_mut(&next) {
+                    self.other_predecessors.push((from.clone(), *others));
+                    *others = Some(self.other_predecessors.len() - 1);
+                }
+                false
+            }
+            MapEntry::Occupied(mut known) => {
+                known.insert(distance);
+                self.predecessors.insert(next, (from.clone(), None));
+                true
+            }
+            MapEntry::Vacant(unknown) => {
+                unknown.insert(distance);
+                self.predecessors.insert(next, (from.clone(), None));
+                true
+            }
+        }
+    }
+
+    /// The states before a state on its shortest paths: the first one found,
+    /// then the others. Empty for the start and for states that weren't reached.
+    pub fn predecessors(&self, state: &S) -> impl Iterator<Item = &S> {
+        let (first, mut others) = match self.predecessors.get(state) {
+            Some((first, others)) => (Some(first), *others),
+            None => (None, None),
+        };
+        first.into_iter().chain(std::iter::from_fn(move || {
+            let (previous, next) = &self.other_predecessors[others?];
+            others = *next;
+            Some(previous)
+        }))
+    }
+
+    /// Distance to the nearest goal, or `None` if no goal was reached.
+    pub fn distance(&self) -> Option<u64> {
+        self.goals.first().and_then(|goal| self.distance_to(goal))
+    }
+
+    pub fn distance_to(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    /// One shortest path from the start to the first goal found, both included.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goals.first().and_then(|goal| self.path_to(goal))
+    }
+
+    /// One shortest path from the start to a reached state, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+
+        let mut path = vec![state.clone()];
+        while let Some((previous, _)) = self.predecessors.get(&path[path.len() - 1]) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state on at least one shortest path to a goal.
+    pub fn states_on_best_paths(&self) -> HashSet<S> {
+        let mut states: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut queue: VecDeque<S> = self.goals.iter().cloned().collect();
+        while let Some(state) = queue.pop_front() {
+            for previous in self.predecessors(&state) {
+                if states.insert(previous.clone()) {
+                    queue.push_back(previous.clone());
+                }
+            }
+        }
+        states
+    }
+
+    /// Every shortest path to a goal. There can be exponentially many, so
+    /// prefer `states_on_best_paths` when only the states matter.
+    pub fn all_paths(&self) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+        for goal in &self.goals {
+            self.collect_paths(vec![goal.clone()], &mut paths);
+        }
+        paths
+    }
+
+    /// Extends a path, built from its end backwards, to every way of reaching
+    /// it from the start.
+    fn collect_paths(&self, path: Vec<S>, paths: &mut Vec<Vec<S>>) {
+        let last = &path[path.len() - 1];
+        if !self.predecessors.contains_key(last) {
+            let mut path = path;
+            path.reverse();
+            paths.push(path);
+            return;
+        }
+        for state in self.predecessors(last) {
+            let mut longer = path.clone();
+            longer.push(state.clone());
+            self.collect_paths(longer, paths);
+        }
+    }
+}
+
+/// Breadth-first search, for moves that all cost 1.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, distance)) = queue.pop_front() {
+        if result.distance().is_some_and(|best| distance > best) {
+            break;
+        }
+        if is_goal(&state) {
+            result.goals.push(state);
+            continue;
+        }
+
+        for next in neighbours(&state) {
+            if result.reach(&state, next.clone(), distance + 1) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm, for moves with a cost each.
+pub fn dijkstra<S, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+/// A* search: Dijkstra's algorithm looking at the states that seem closest to
+/// a goal first. `heuristic` estimates the distance left and must never be
+/// more than the real one, or the result may not be the shortest.
+///
+/// Panics on a move that costs 0: with free moves, states at the same distance
+/// could be each other's predecessors and the paths would loop forever.
+pub fn astar<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut queue = BinaryHeap::from([Reverse(Entry {
+        estimate: heuristic(&start),
+        distance: 0,
+        state: start,
+    })]);
+
+    while let Some(Reverse(Entry {
+        estimate,
+        distance,
+        state,
+    })) = queue.pop()
+    {
+        if result
+            .distance_to(&state)
+            .is_some_and(|known| distance > known)
+        {
+            // Already reached by a shorter path
+            continue;
+        }
+        if result.distance().is_some_and(|best| estimate > best) {
+            break;
+        }
+        if is_goal(&state) {
+            result.goals.push(state);
+            continue;
+        }
+
+        for (next, cost) in neighbours(&state) {
+            assert!(cost > 0, "Moves must cost at least 1");
+            let next_distance = distance + cost;
+            if result.reach(&state, next.clone(), next_distance) {
+                queue.push(Reverse(Entry {
+                    estimate: next_distance + heuristic(&next),
+                    distance: next_distance,
+                    state: next,
+                }));
+            }
+        }
+    }
+
+    result
+}
+
+/// A state waiting in the A* queue, ordered by its estimated total distance
+/// only, so states don't need to be `Ord`.
+struct Entry<S> {
+    estimate: u64,
+    distance: u64,
+    state: S,
+}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S> Eq for Entry<S> {}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.estimate.cmp(&other.estimate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::{parse_string_grid, Direction, Grid, Point};
+
+    fn open_neighbours(maze: &Grid<char>, p: &Point) -> Vec<Point> {
+        maze.neighbours_iter(p, false)
+            .filter(|n| maze[*n] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = parse_string_grid("S..\n.#.\n..E");
+        let (start, end) = (Point::new(0, 0), Point::new(2, 2));
+        let result = bfs(start, |p| open_neighbours(&maze, p), |p| *p == end);
+
+        assert_eq!(result.distance(), Some(4));
+        assert_eq!(result.goals, vec![end]);
+        let path = result.path().unwrap();
+        assert_eq!((path[0], path[4]), (start, end));
+        assert_eq!(result.all_paths().len(), 2);
+        assert_eq!(result.states_on_best_paths().len(), 8);
+        assert_eq!(result.predecessors(&end).count(), 2);
+        assert_eq!(result.predecessors(&start).count(), 0);
+        assert_eq!(result.distance_to(&Point::new(2, 0)), Some(2));
+
+        // A goal that never matches gives the distance to everything
+        let result = bfs(start, |p| open_neighbours(&maze, p), |_| false);
+        assert_eq!(result.distances.len(), 8);
+        assert_eq!(result.distance(), None);
+        assert_eq!(result.path(), None);
+        assert_eq!(result.path_to(&end).map(|path| path.len()), Some(5));
+    }
+
+    #[test]
+    fn test_weighted() {
+        // Going straight costs 1 and turning costs 10 more
+        let maze = parse_string_grid("S...\n....\n...E");
+        let (start, end) = (Point::new(0, 0), Point::new(3, 2));
+        let moves = |&(p, facing): &(Point, Direction)| {
+            Direction::cardinals()
+                .map(move |dir| {
+                    (
+                        (p.step(dir), dir),
+                        1 + 10 * facing.turns_between(dir) as u64,
+                    )
+                })
+                .filter(|((next, _), _)| maze.in_bounds(next))
+                .collect::<Vec<_>>()
+        };
+
+        let result = dijkstra((start, Direction::E), moves, |(p, _)| *p == end);
+        assert_eq!(result.distance(), Some(15));
+        assert_eq!(result.goals, vec![(end, Direction::S)]);
+        assert_eq!(result.all_paths().len(), 1);
+
+        let result = astar(
+            (start, Direction::E),
+            moves,
+            |(p, _)| p.manhattan_distance(&end) as u64,
+            |(p, _)| *p == end,
+        );
+        assert_eq!(result.distance(), Some(15));
+        assert_eq!(result.path().unwrap().len(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Moves must cost at least 1")]
+    fn test_free_moves() {
+        dijkstra(0, |&n: &i32| [(n + 1, 0), (n - 1, 0)], |&n| n == 3);
+    }
+}
@@ -0,0 +1,190 @@
+use std::error::Error;
+
+use crate::answer::Answer;
+use crate::input::{self, InputSource};
+use crate::ledger::Ledger;
+use crate::report::{self, Record};
+use crate::solvers::{self, SolveOutcome};
+use crate::{OutputFormat, Part};
+
+/// The outcome of checking one part against the ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail(_) => "FAIL",
+            Status::Missing => "missing",
+        }
+    }
+
+    fn verdict(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail(_) => "fail",
+            Status::Missing => "missing",
+        }
+    }
+}
+
+/// Runs every registered solver (optionally for a single year) against its real
+/// input and compares the result with the ledger, printing a pass/fail/missing
+/// matrix, or a record per part. Returns an error if any part fails.
+pub fn verify(year: Option<i32>, format: OutputFormat) -> Result<(), Box<dyn Error>> {
+    let days = solvers::days(year);
+    if days.is_empty() {
+        return Err("No solvers registered for the requested year".into());
+    }
+
+    let mut years: Vec<i32> = days.iter().map(|(y, _)| *y).collect();
+    years.dedup();
+
+    let mut failures = Vec::new();
+    let (mut passed, mut missing) = (0, 0);
+    let mut records = Vec::new();
+
+    for year in years {
+        let ledger = Ledger::load(year)?;
+
+        let table = format == OutputFormat::Table;
+        if table {
+            println!("{:<8}Part 1    Part 2", year);
+        }
+        for &(_, day) in days.iter().filter(|(y, _)| *y == year) {
+            let mut row = format!("Day {:02}  ", day);
+            for part in [Part::One, Part::Two] {
+                let (status, record) = check(year, day, part, &ledger);
+                records.push(record);
+                row.push_str(&format!("{:<10}", status.label()));
+                match status {
+                    Status::Pass => passed += 1,
+                    Status::Missing => missing += 1,
+                    Status::Fail(reason) => {
+                        failures.push(format!("{} day {:02} part {}: {}", year, day, part, reason))
+                    }
+                }
+            }
+            if table {
+                println!("{}", row.trim_end());
+            }
+        }
+        if table {
+            println!();
+        }
+    }
+
+    if format != OutputFormat::Table {
+        print!("{}", report::render(&records, format)?);
+    } else {
+        for failure in &failures {
+            println!("{}", failure);
+        }
+        println!(
+            "Passed: {}, Failed: {}, Missing: {}",
+            passed,
+            failures.len(),
+            missing
+        );
+    }
+
+    if !failures.is_empty() {
+        return Err(format!("{} part(s) failed verification", failures.len()).into());
+    }
+    Ok(())
+}
+
+/// Checks a single part, returning its status along with the record of the
+/// run. Parts without a downloaded input or a recorded answer are reported as
+/// missing without running the solver.
+pub fn check(year: i32, day: i32, part: Part, ledger: &Ledger) -> (Status, Record) {
+    let path = input::input_path(year, day);
+    let expected = ledger.get(day, part);
+
+    let (status, mut record) = match expected {
+        Some(expected) if input::has_input(year, day) => {
+            match InputSource::Puzzle.read(year, day) {
+                Err(e) => (
+                    Status::Fail(e.to_string()),
+                    Record::skipped(year, day, part, &path, Some(e.to_string())),
+                ),
+                Ok(input) => {
+                    let run = solvers::run(year, day, &[part], &input);
+                    let (_, outcome, elapsed) = &run.parts[0];
+                    let status = compare(outcome, expected);
+                    let record = Record::new(year, day, part, &path, outcome, *elapsed)
+                        .with_parse(run.parse);
+                    (status, record)
+                }
+            }
+        }
+        _ => (
+            Status::Missing,
+            Record::skipped(year, day, part, &path, None),
+        ),
+    };
+
+    record.expected = expected.map(|answer| answer.to_string());
+    record.verdict = Some(status.verdict());
+    (status, record)
+}
+
+/// Compares a solver's outcome with the accepted answer.
+fn compare(outcome: &SolveOutcome, expected: &Answer) -> Status {
+    match outcome {
+        SolveOutcome::Answer(answer) if answer == expected => Status::Pass,
+        SolveOutcome::Answer(answer) => {
+            Status::Fail(format!("expected {}, got {}", expected, answer))
+        }
+        SolveOutcome::Error(e) => Status::Fail(e.clone()),
+        SolveOutcome::Panic(message) => Status::Fail(format!("solver panicked: {}", message)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        let expected = Answer::Int(42);
+        assert_eq!(
+            compare(&SolveOutcome::Answer(Answer::Int(42)), &expected),
+            Status::Pass
+        );
+        assert_eq!(
+            compare(&SolveOutcome::Answer(Answer::from("42x")), &expected),
+            Status::Fail("expected 42, got 42x".to_string())
+        );
+        assert_eq!(
+            compare(&SolveOutcome::Error("bad input".to_string()), &expected),
+            Status::Fail("bad input".to_string())
+        );
+        assert_eq!(
+            compare(&SolveOutcome::Panic("oops".to_string()), &expected),
+            Status::Fail("solver panicked: oops".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check_missing() {
+        // No ledger entry: the solver isn't run
+        let ledger = Ledger::parse(2024, "1 2 31").unwrap();
+        let (status, record) = check(2024, 1, Part::One, &ledger);
+        assert_eq!(status, Status::Missing);
+        assert_eq!(record.expected, None);
+        assert_eq!(record.verdict, Some("missing"));
+
+        // A ledger entry but no downloaded input
+        if !input::has_input(2024, 1) {
+            let (status, record) = check(2024, 1, Part::Two, &ledger);
+            assert_eq!(status, Status::Missing);
+            assert_eq!(record.expected, Some("31".to_string()));
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::error::Error;
+
+use crate::answer::Answer;
+use crate::input::PuzzleInput;
+use crate::params::Param;
+use crate::solvers::{run_staged, SolveRun};
+use crate::utils::parsing::{read_graph, Graph};
+use crate::Part;
+
+pub const PARAMS: &[Param] = &[];
+
+pub fn run(parts: &[Part], input: &PuzzleInput) -> SolveRun {
+    run_staged(parts, input, parse, main)
+}
+
+/// Parses the input once for both parts, as the neighbours of each node in a
+/// list of `a-b` edges.
+pub fn parse(input: &PuzzleInput) -> Result<Graph, Box<dyn Error>> {
+    Ok(read_graph(&input.text))
+}
+
+pub fn main(part: Part, graph: &Graph) -> Result<Answer, Box<dyn Error>> {
+    let res = match part {
+        Part::One => part_1(graph),
+        Part::Two => part_2(graph),
+    };
+
+    Ok(res)
+}
+
+pub fn part_1(graph: &Graph) -> Answer {
+    return 0.into();
+}
+
+pub fn part_2(graph: &Graph) -> Answer {
+    return 0.into();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config;
+    use std::fs;
+
+    const EXAMPLE_FILE: &str = "year_0000/day_00/input/example.txt";
+
+    #[test]
+    fn test_part_1() {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
+            Ok(input) => {
+                let result = part_1(&read_graph(&input));
+                assert_eq!(result, Answer::from(0 /* expected part_1 */));
+            }
+            Err(e) => {
+                eprintln!("Failed to read test input file: {}", e);
+                panic!("Test input file missing or unreadable");
+            }
+        }
+    }
+
+    #[test]
+    fn test_part_2() {
+        match fs::read_to_string(config::input_dir().join(EXAMPLE_FILE)) {
+            Ok(input) => {
+                let result = part_2(&read_graph(&input));
+                assert_eq!(result, Answer::from(0 /* expected part_2 */));
+            }
+            Err(e) => {
+                eprintln!("Failed to read test input file: {}", e);
+                panic!("Test input file missing or unreadable");
+            }
+        }
+    }
+}